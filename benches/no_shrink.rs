@@ -0,0 +1,36 @@
+//! Quantifies the speedup of generating values that never shrink.
+
+use criterion::Criterion;
+use criterion::criterion_group;
+use criterion::criterion_main;
+use proptest::strategy::Strategy;
+use proptest::strategy::ValueTree;
+use proptest::test_runner::TestRunner;
+use proptest_arbitrary_adapter::arb_sized;
+use proptest_arbitrary_adapter::arb_sized_no_shrink;
+
+/// Large and expensive to clone.
+type Large = Vec<[u64; 16]>;
+
+const SIZE: usize = 64 * 1024;
+
+fn generate(c: &mut Criterion) {
+    let mut group = c.benchmark_group("generate");
+
+    let strategy = arb_sized::<Large>(SIZE);
+    let mut runner = TestRunner::deterministic();
+    group.bench_function("shrinking", |b| {
+        b.iter(|| strategy.new_tree(&mut runner).unwrap().current())
+    });
+
+    let strategy = arb_sized_no_shrink::<Large>(SIZE);
+    let mut runner = TestRunner::deterministic();
+    group.bench_function("no shrink", |b| {
+        b.iter(|| strategy.new_tree(&mut runner).unwrap().current())
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, generate);
+criterion_main!(benches);
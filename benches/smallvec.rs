@@ -0,0 +1,35 @@
+//! Quantifies the effect of the feature `smallvec` on generating and cloning
+//! trees of simple types. Compare runs with and without the feature.
+
+use arbitrary::Arbitrary;
+use criterion::Criterion;
+use criterion::criterion_group;
+use criterion::criterion_main;
+use proptest::strategy::Strategy;
+use proptest::test_runner::TestRunner;
+use proptest_arbitrary_adapter::ArbInterop;
+use proptest_arbitrary_adapter::arb;
+
+#[derive(Debug, Clone, Arbitrary)]
+#[expect(dead_code, reason = "only generated")]
+struct Rgb(u8, u8, u8);
+
+fn bench<A: ArbInterop>(c: &mut Criterion, name: &str) {
+    let strategy = arb::<A>();
+    let mut runner = TestRunner::deterministic();
+    c.bench_function(&format!("generate {name}"), |b| {
+        b.iter(|| strategy.new_tree(&mut runner).unwrap())
+    });
+
+    let tree = strategy.new_tree(&mut runner).unwrap();
+    c.bench_function(&format!("clone {name}"), |b| b.iter(|| tree.clone()));
+}
+
+fn generate_and_clone(c: &mut Criterion) {
+    bench::<u8>(c, "u8");
+    bench::<u32>(c, "u32");
+    bench::<Rgb>(c, "Rgb");
+}
+
+criterion_group!(benches, generate_and_clone);
+criterion_main!(benches);
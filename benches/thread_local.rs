@@ -0,0 +1,52 @@
+//! Quantifies the speedup of reusing a buffer for random data that is
+//! rejected most of the time.
+
+use arbitrary::Arbitrary;
+use arbitrary::Unstructured;
+use criterion::Criterion;
+use criterion::criterion_group;
+use criterion::criterion_main;
+use proptest::strategy::Strategy;
+use proptest::strategy::ValueTree;
+use proptest::test_runner::TestRunner;
+use proptest_arbitrary_adapter::ArbStrategy;
+use proptest_arbitrary_adapter::ThreadLocalArbStrategy;
+use proptest_arbitrary_adapter::arb_sized;
+
+/// Rejects all random data but the one in 16 starting with a byte whose low
+/// nibble is zero.
+#[derive(Debug, Clone)]
+struct Rare;
+
+impl<'a> Arbitrary<'a> for Rare {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        if u8::arbitrary(u)? & 0x0f != 0 {
+            return Err(arbitrary::Error::IncorrectFormat);
+        }
+
+        Ok(Self)
+    }
+}
+
+const SIZE: usize = 64 * 1024;
+
+fn generate(c: &mut Criterion) {
+    let mut group = c.benchmark_group("generate");
+
+    let strategy: ArbStrategy<Rare> = arb_sized(SIZE);
+    let mut runner = TestRunner::deterministic();
+    group.bench_function("allocating", |b| {
+        b.iter(|| strategy.new_tree(&mut runner).unwrap().current())
+    });
+
+    let strategy = ThreadLocalArbStrategy::new(strategy);
+    let mut runner = TestRunner::deterministic();
+    group.bench_function("thread local", |b| {
+        b.iter(|| strategy.new_tree(&mut runner).unwrap().current())
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, generate);
+criterion_main!(benches);
@@ -0,0 +1,61 @@
+//! Provides `#[derive(ArbInterop)]` for the crate
+//! [`proptest-arbitrary-adapter`](https://docs.rs/proptest-arbitrary-adapter).
+//! Use it through that crate's feature `derive`.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use quote::quote_spanned;
+use syn::DeriveInput;
+use syn::parse_macro_input;
+use syn::parse_quote;
+use syn::spanned::Spanned;
+
+/// Checks that the annotated type satisfies all requirements of
+/// `proptest_arbitrary_adapter::ArbInterop`, which is implemented
+/// automatically for all such types.
+///
+/// The derive generates no code that ends up in the binary. Every unmet
+/// requirement results in a separate compile error naming it. Type parameters
+/// are assumed to satisfy `ArbInterop` themselves.
+#[proc_macro_derive(ArbInterop)]
+pub fn derive_arb_interop(input: TokenStream) -> TokenStream {
+    let mut input = parse_macro_input!(input as DeriveInput);
+
+    if let Some(lifetime) = input.generics.lifetimes().next() {
+        let message =
+            "ArbInterop requires `'static` types, so they cannot have lifetime parameters";
+        return syn::Error::new(lifetime.span(), message)
+            .to_compile_error()
+            .into();
+    }
+
+    let adapter = quote!(::proptest_arbitrary_adapter);
+    for param in input.generics.type_params_mut() {
+        param.bounds.push(parse_quote!(#adapter::ArbInterop));
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let span = name.span();
+    let checks = [
+        quote_spanned!(span=> require_arbitrary::<#name #ty_generics>();),
+        quote_spanned!(span=> require_static::<#name #ty_generics>();),
+        quote_spanned!(span=> require_debug::<#name #ty_generics>();),
+        quote_spanned!(span=> require_clone::<#name #ty_generics>();),
+    ];
+
+    quote! {
+        const _: () = {
+            fn require_arbitrary<T: for<'a> #adapter::__private::arbitrary::Arbitrary<'a>>() {}
+            fn require_static<T: 'static>() {}
+            fn require_debug<T: ::core::fmt::Debug>() {}
+            fn require_clone<T: ::core::clone::Clone>() {}
+
+            #[allow(dead_code)]
+            fn check #impl_generics () #where_clause {
+                #(#checks)*
+            }
+        };
+    }
+    .into()
+}
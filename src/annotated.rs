@@ -0,0 +1,90 @@
+use alloc::string::String;
+
+use proptest::strategy::Strategy;
+use proptest::test_runner::TestRunner;
+
+use crate::ArbInterop;
+use crate::ArbStrategy;
+use crate::ArbValueTree;
+
+/// A [`proptest::strategy::Strategy`] like [`ArbStrategy`] that
+/// [annotates](ArbValueTree::annotate) each of its trees with a label. See
+/// [`ArbStrategy::annotate`].
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct AnnotatedArbStrategy<A: ArbInterop> {
+    strategy: ArbStrategy<A>,
+    label: String,
+}
+
+impl<A: ArbInterop> ArbStrategy<A> {
+    /// Turns this strategy into an [`AnnotatedArbStrategy`], which attaches
+    /// the given `label` to each of its trees.
+    pub fn annotate(self, label: impl Into<String>) -> AnnotatedArbStrategy<A> {
+        AnnotatedArbStrategy {
+            strategy: self,
+            label: label.into(),
+        }
+    }
+}
+
+impl<A: ArbInterop> Strategy for AnnotatedArbStrategy<A> {
+    type Tree = ArbValueTree<A>;
+    type Value = A;
+
+    fn new_tree(&self, run: &mut TestRunner) -> proptest::strategy::NewTree<Self> {
+        let mut tree = self.strategy.new_tree(run)?;
+        tree.annotate(self.label.clone());
+
+        Ok(tree)
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, feature(coverage_attribute))]
+mod tests {
+    use alloc::format;
+    use alloc::string::ToString;
+
+    use proptest::strategy::ValueTree;
+
+    use super::*;
+    use crate::arb_sized;
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn trees_are_labeled() {
+        let mut tree = arb_sized::<u8>(1)
+            .annotate("handshake")
+            .new_tree(&mut TestRunner::deterministic())
+            .unwrap();
+        assert_eq!(Some("handshake"), tree.label());
+
+        tree.simplify();
+        assert_eq!(Some("handshake"), tree.label());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn labels_appear_in_output() {
+        let mut tree = ArbValueTree::<u8>::new(alloc::vec![7]).unwrap();
+        assert_eq!(None, tree.label());
+        tree.annotate("first");
+        tree.annotate("second");
+
+        assert_eq!(
+            "second: ArbValueTree { size: 1/1, value: 7 }",
+            tree.to_string()
+        );
+        assert!(format!("{tree:?}").contains("label: Some(\"second\")"));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn labels_do_not_affect_equality() {
+        let tree = ArbValueTree::<u8>::new(alloc::vec![7]).unwrap();
+        let mut labeled = tree.clone();
+        labeled.annotate("label");
+
+        assert_eq!(tree, labeled);
+    }
+}
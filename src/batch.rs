@@ -0,0 +1,166 @@
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+use proptest::prelude::RngCore;
+use proptest::strategy::Strategy;
+use proptest::strategy::ValueTree;
+use proptest::test_runner::TestRunner;
+
+use crate::ArbInterop;
+use crate::ArbStrategy;
+use crate::ArbValueTree;
+
+/// A [`proptest::strategy::Strategy`] for [`Vec`]s of exactly `n` values of a
+/// given [`arbitrary::Arbitrary`] type, generated from one buffer of random
+/// data. See [`ArbStrategy::batch`].
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct BatchArbStrategy<A: ArbInterop> {
+    strategy: ArbStrategy<A>,
+    n: usize,
+}
+
+impl<A: ArbInterop> Strategy for BatchArbStrategy<A> {
+    type Tree = BatchValueTree<A>;
+    type Value = Vec<A>;
+
+    fn new_tree(&self, run: &mut TestRunner) -> proptest::strategy::NewTree<Self> {
+        let n = self.n;
+        let fill_bytes = |run: &mut TestRunner, bytes: &mut Vec<u8>| {
+            bytes.resize(n * bytes.len(), 0);
+            run.rng().fill_bytes(bytes);
+        };
+        let build = |bytes: Vec<u8>| {
+            let size = bytes.len().checked_div(n).unwrap_or(0);
+            (0..n)
+                .map(|i| ArbValueTree::new(bytes[i * size..(i + 1) * size].to_vec()))
+                .collect::<Result<Vec<_>, _>>()
+        };
+        let mut trees = self.strategy.generate(run, fill_bytes, || (), build)?;
+        for tree in &mut trees {
+            self.strategy.configure_tree(tree);
+        }
+
+        Ok(BatchValueTree {
+            trees,
+            index: 0,
+            last_simplified: None,
+        })
+    }
+}
+
+/// The [`proptest::strategy::ValueTree`] of a [`BatchArbStrategy`].
+///
+/// Shrinks one element at a time, in order: first the element at index 0 as
+/// far as possible, then the one at index 1, and so on.
+#[derive(Clone, Debug)]
+pub struct BatchValueTree<A: Debug> {
+    trees: Vec<ArbValueTree<A>>,
+
+    /// The index of the element currently being shrunk.
+    index: usize,
+
+    /// The index of the element most recently simplified, if any.
+    last_simplified: Option<usize>,
+}
+
+impl<A: ArbInterop> ValueTree for BatchValueTree<A> {
+    type Value = Vec<A>;
+
+    fn current(&self) -> Self::Value {
+        self.trees.iter().map(ValueTree::current).collect()
+    }
+
+    fn simplify(&mut self) -> bool {
+        while let Some(tree) = self.trees.get_mut(self.index) {
+            if tree.simplify() {
+                self.last_simplified = Some(self.index);
+                return true;
+            }
+            self.index += 1;
+        }
+
+        false
+    }
+
+    fn complicate(&mut self) -> bool {
+        let Some(index) = self.last_simplified.take() else {
+            return false;
+        };
+
+        self.trees[index].complicate()
+    }
+}
+
+impl<A: ArbInterop> ArbStrategy<A> {
+    /// Generates exactly `n` values from one buffer of random data, `n` times
+    /// the size this strategy samples, split into equal chunks.
+    ///
+    /// Cheaper than generating `n` values independently, like
+    /// [`repeat`](Self::repeat) does, since the random data is sampled at once.
+    /// If the [`arbitrary::Arbitrary`] implementation rejects any chunk, the
+    /// whole buffer is retried. Elements shrink independently, in order.
+    pub fn batch(self, n: usize) -> BatchArbStrategy<A> {
+        BatchArbStrategy { strategy: self, n }
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, feature(coverage_attribute))]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+    use crate::arb_sized;
+    use crate::test_util::Len;
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn buffer_is_split_into_equal_chunks() {
+        let strategy = arb_sized::<Len>(4).batch(100);
+        let tree = strategy.new_tree(&mut TestRunner::deterministic()).unwrap();
+
+        assert_eq!(vec![Len(4); 100], tree.current());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn empty_batches_are_empty() {
+        let mut runner = TestRunner::deterministic();
+        let tree = arb_sized::<Len>(4).batch(0).new_tree(&mut runner).unwrap();
+        assert!(tree.current().is_empty());
+
+        let tree = arb_sized::<Len>(0).batch(3).new_tree(&mut runner).unwrap();
+        assert_eq!(vec![Len(0); 3], tree.current());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn elements_shrink_in_order() {
+        let strategy = arb_sized::<u8>(1).batch(3);
+        let mut tree = strategy.new_tree(&mut TestRunner::deterministic()).unwrap();
+        let original = tree.current();
+        assert!(original.iter().all(|&byte| byte != 0));
+
+        while tree.current()[0] != 0 {
+            assert!(tree.simplify());
+            assert_eq!(original[1..], tree.current()[1..]);
+        }
+
+        while tree.simplify() {}
+        assert_eq!(vec![0; 3], tree.current());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn complicating_undoes_the_last_simplification() {
+        let strategy = arb_sized::<u8>(1).batch(3);
+        let mut tree = strategy.new_tree(&mut TestRunner::deterministic()).unwrap();
+        let original = tree.current();
+        assert!(!tree.complicate());
+
+        assert!(tree.simplify());
+        assert!(tree.complicate());
+        assert_eq!(original, tree.current());
+        assert!(!tree.complicate());
+    }
+}
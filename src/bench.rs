@@ -0,0 +1,71 @@
+//! Generating benchmark inputs outside of property tests, for example in
+//! [criterion](https://crates.io/crates/criterion) benchmarks.
+//!
+//! The inputs are sized like [`arb`] sizes them and come from a
+//! [deterministic](TestRunner::deterministic) runner, so every benchmark run
+//! measures the same inputs.
+//!
+//! ```
+//! use criterion::Criterion;
+//! use proptest_arbitrary_adapter::bench::arb_bench_inputs;
+//!
+//! fn bench_sort(c: &mut Criterion) {
+//!     let inputs = arb_bench_inputs::<Vec<u32>>(100);
+//!     c.bench_function("sort", |b| {
+//!         b.iter(|| {
+//!             for input in &inputs {
+//!                 let mut input = input.clone();
+//!                 input.sort_unstable();
+//!             }
+//!         })
+//!     });
+//! }
+//! ```
+
+use alloc::vec::Vec;
+
+use proptest::test_runner::TestRunner;
+
+use crate::ArbInterop;
+use crate::arb;
+
+/// Generates one benchmark input. Returns the same value on every call.
+///
+/// # Panics
+///
+/// Panics if generating the value fails, for example because the
+/// [`arbitrary::Arbitrary`] implementation rejects too much random data.
+pub fn arb_bench_input<A: ArbInterop>() -> A {
+    let mut inputs = arb_bench_inputs(1);
+    inputs.remove(0)
+}
+
+/// Generates `n` independent benchmark inputs. Returns the same values on
+/// every call, and shorter calls return a prefix of longer ones.
+///
+/// # Panics
+///
+/// Panics if generating a value fails, for example because the
+/// [`arbitrary::Arbitrary`] implementation rejects too much random data.
+pub fn arb_bench_inputs<A: ArbInterop>(n: usize) -> Vec<A> {
+    arb::<A>()
+        .into_iter_with_runner(TestRunner::deterministic())
+        .take(n)
+        .collect()
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, feature(coverage_attribute))]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn inputs_are_reproducible() {
+        let inputs = arb_bench_inputs::<Vec<u32>>(10);
+        assert_eq!(10, inputs.len());
+        assert_eq!(inputs, arb_bench_inputs::<Vec<u32>>(10));
+        assert_eq!(inputs[..3], arb_bench_inputs::<Vec<u32>>(3));
+        assert_eq!(inputs[0], arb_bench_input::<Vec<u32>>());
+    }
+}
@@ -0,0 +1,137 @@
+use proptest::prelude::RngCore;
+use proptest::test_runner::TestRunner;
+
+use crate::ArbInterop;
+use crate::ArbStrategy;
+use crate::ArbValueTree;
+use crate::arb;
+use crate::assert_probability;
+
+/// A [`proptest::strategy::Strategy`] that over-represents the extreme byte
+/// values `0x00` and `0xFF` in the random data fed to the
+/// [`arbitrary::Arbitrary`] implementation.
+///
+/// Bugs often hide at boundary values. Biasing the random data towards extreme
+/// bytes makes boundary values more likely for many [`arbitrary::Arbitrary`]
+/// implementations, without having to change them. See [`arb_biased`].
+#[derive(Clone, PartialEq, PartialOrd, Debug)]
+pub struct BiasedArbStrategy<A: ArbInterop> {
+    strategy: ArbStrategy<A>,
+
+    /// The probability with which any random byte is replaced by an extreme
+    /// byte value.
+    probability: f64,
+}
+
+impl<A: ArbInterop> BiasedArbStrategy<A> {
+    /// The probability used by [`BiasedArbStrategy::default`].
+    pub const DEFAULT_PROBABILITY: f64 = 0.3;
+
+    /// Biases the random data of the given `strategy`: with the given
+    /// `probability`, every random byte is replaced by either `0x00` or `0xFF`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `probability` is not in the range `[0.0, 1.0]`.
+    pub fn new(strategy: ArbStrategy<A>, probability: f64) -> Self {
+        assert_probability(probability);
+
+        Self {
+            strategy,
+            probability,
+        }
+    }
+
+    fn bias(&self, run: &mut TestRunner, bytes: &mut [u8]) {
+        let threshold = self.probability * (f64::from(u32::MAX) + 1.0);
+        for byte in bytes {
+            let rng = run.rng();
+            *byte = rng.next_u32() as u8;
+            if f64::from(rng.next_u32()) < threshold {
+                // The byte is uniformly random, and so is its lowest bit.
+                *byte = if *byte & 1 == 0 { 0x00 } else { 0xFF };
+            }
+        }
+    }
+}
+
+impl<A: ArbInterop> Default for BiasedArbStrategy<A> {
+    fn default() -> Self {
+        arb_biased(Self::DEFAULT_PROBABILITY)
+    }
+}
+
+impl<A: ArbInterop> proptest::strategy::Strategy for BiasedArbStrategy<A> {
+    type Tree = ArbValueTree<A>;
+    type Value = A;
+
+    fn new_tree(&self, run: &mut TestRunner) -> proptest::strategy::NewTree<Self> {
+        self.strategy
+            .new_tree_with(run, |run, bytes| self.bias(run, bytes))
+    }
+}
+
+/// Constructs a [`BiasedArbStrategy`] for a given [`arbitrary::Arbitrary`]
+/// type, sized like [`arb`] does. Every random byte is replaced by either
+/// `0x00` or `0xFF` with probability `p`.
+///
+/// # Panics
+///
+/// Panics if `p` is not in the range `[0.0, 1.0]`.
+pub fn arb_biased<A: ArbInterop>(p: f64) -> BiasedArbStrategy<A> {
+    BiasedArbStrategy::new(arb(), p)
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, feature(coverage_attribute))]
+mod tests {
+    use proptest::prelude::*;
+    use proptest::strategy::ValueTree;
+
+    use super::*;
+    use crate::ArbConfig;
+
+    #[derive(Debug, Clone, arbitrary::Arbitrary)]
+    struct Bytes([u8; 64]);
+
+    fn num_extreme_bytes(strategy: BiasedArbStrategy<Bytes>) -> usize {
+        let mut runner = TestRunner::deterministic();
+        (0..100)
+            .flat_map(|_| strategy.new_tree(&mut runner).unwrap().current().0)
+            .filter(|&byte| byte == 0x00 || byte == 0xFF)
+            .count()
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn extreme_bytes_are_over_represented() {
+        // Out of 6400 uniformly random bytes, roughly 50 are extreme.
+        assert!(num_extreme_bytes(arb_biased(0.0)) < 100);
+        assert!(num_extreme_bytes(BiasedArbStrategy::default()) > 1000);
+        assert_eq!(6400, num_extreme_bytes(arb_biased(1.0)));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn configuration_of_wrapped_strategy_is_respected() {
+        let config = ArbConfig::builder().size(0).build();
+        let strategy = BiasedArbStrategy::<Bytes>::new(ArbStrategy::with_config(config), 1.0);
+        let tree = strategy.new_tree(&mut TestRunner::deterministic()).unwrap();
+
+        assert_eq!(0, tree.current_size());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[should_panic]
+    fn probability_must_not_exceed_one() {
+        let _ = arb_biased::<Bytes>(1.5);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[should_panic]
+    fn probability_must_not_be_nan() {
+        let _ = arb_biased::<Bytes>(f64::NAN);
+    }
+}
@@ -0,0 +1,101 @@
+use alloc::format;
+
+use proptest::strategy::Strategy;
+use proptest::test_runner::TestRunner;
+
+use crate::ArbInterop;
+use crate::ArbStrategy;
+use crate::ArbValueTree;
+use crate::arb;
+
+/// A [`proptest::strategy::Strategy`] like [`ArbStrategy`] that rejects values
+/// whose [`arbitrary::Arbitrary`] implementation reads more than a given
+/// number of bytes. See [`arb_bounded`].
+///
+/// The number of bytes read is a measure of a value's complexity, see
+/// [`ArbValueTree::bytes_consumed`]. Only generated values are bounded, not
+/// the values shrinking leads to.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct BoundedArbStrategy<A: ArbInterop> {
+    strategy: ArbStrategy<A>,
+    max_complexity: usize,
+}
+
+impl<A: ArbInterop> BoundedArbStrategy<A> {
+    /// Generates values like the given `strategy`, rejecting those that read
+    /// more than `max_complexity` bytes of random data.
+    pub fn new(strategy: ArbStrategy<A>, max_complexity: usize) -> Self {
+        Self {
+            strategy,
+            max_complexity,
+        }
+    }
+}
+
+impl<A: ArbInterop> Strategy for BoundedArbStrategy<A> {
+    type Tree = ArbValueTree<A>;
+    type Value = A;
+
+    fn new_tree(&self, run: &mut TestRunner) -> proptest::strategy::NewTree<Self> {
+        loop {
+            let tree = self.strategy.new_tree(run)?;
+            let complexity = tree.bytes_consumed();
+            if complexity <= self.max_complexity {
+                return Ok(tree);
+            }
+
+            let name = self.strategy.origin();
+            let max_complexity = self.max_complexity;
+            run.reject_local(format!(
+                "{name}: complexity {complexity} exceeds {max_complexity}"
+            ))?;
+        }
+    }
+}
+
+/// Constructs a [`BoundedArbStrategy`] for a given [`arbitrary::Arbitrary`]
+/// type, sized like [`arb`] does, that only generates values reading at most
+/// `max_complexity` bytes of random data.
+pub fn arb_bounded<A: ArbInterop>(max_complexity: usize) -> BoundedArbStrategy<A> {
+    BoundedArbStrategy::new(arb(), max_complexity)
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, feature(coverage_attribute))]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn complexity_is_bounded() {
+        let strategy = arb_bounded::<Vec<u8>>(16);
+        let mut runner = TestRunner::deterministic();
+        for _ in 0..100 {
+            let tree = strategy.new_tree(&mut runner).unwrap();
+            assert!(tree.bytes_consumed() <= 16);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn consumed_bytes_are_counted() {
+        let tree = ArbValueTree::<u16>::new(alloc::vec![1; 8]).unwrap();
+        assert_eq!(8, tree.current_size());
+        assert_eq!(2, tree.bytes_consumed());
+
+        let tree = ArbValueTree::<(u8, u32)>::new(alloc::vec![1; 3]).unwrap();
+        assert_eq!(3, tree.bytes_consumed());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn unsatisfiable_bound_fails() {
+        let reason = arb_bounded::<u64>(7)
+            .new_tree(&mut TestRunner::deterministic())
+            .unwrap_err();
+
+        assert!(reason.message().contains("Too many local rejects"));
+    }
+}
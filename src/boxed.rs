@@ -0,0 +1,125 @@
+use core::fmt::Debug;
+use core::fmt::Display;
+use core::fmt::Formatter;
+
+use proptest::strategy::BoxedStrategy;
+use proptest::strategy::NewTree;
+use proptest::strategy::Strategy;
+use proptest::test_runner::TestRunner;
+
+use crate::ArbInterop;
+use crate::ArbStrategy;
+
+impl<A: ArbInterop> From<ArbStrategy<A>> for BoxedStrategy<A> {
+    fn from(strategy: ArbStrategy<A>) -> Self {
+        strategy.boxed()
+    }
+}
+
+/// A type-erased [`ArbStrategy`] that remembers the
+/// [name](ArbStrategy::name) of the original strategy, which its
+/// [`Display`] implementation shows.
+///
+/// A [`BoxedStrategy`] cannot be turned back into an [`ArbStrategy`], since
+/// the concrete type of the strategy is erased. Keep the original strategy
+/// around if you need it later.
+#[derive(Clone)]
+pub struct ArbBoxed<A> {
+    name: &'static str,
+    strategy: BoxedStrategy<A>,
+}
+
+impl<A> ArbBoxed<A> {
+    /// The name of the original strategy.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The underlying [`BoxedStrategy`].
+    pub fn into_inner(self) -> BoxedStrategy<A> {
+        self.strategy
+    }
+}
+
+impl<A: ArbInterop> From<ArbStrategy<A>> for ArbBoxed<A> {
+    fn from(strategy: ArbStrategy<A>) -> Self {
+        Self {
+            name: strategy.name(),
+            strategy: strategy.boxed(),
+        }
+    }
+}
+
+impl<A> From<ArbBoxed<A>> for BoxedStrategy<A> {
+    fn from(boxed: ArbBoxed<A>) -> Self {
+        boxed.strategy
+    }
+}
+
+impl<A> Display for ArbBoxed<A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "boxed {}", self.name)
+    }
+}
+
+impl<A> Debug for ArbBoxed<A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ArbBoxed")
+            .field("name", &self.name)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<A: Debug> Strategy for ArbBoxed<A> {
+    type Tree = <BoxedStrategy<A> as Strategy>::Tree;
+    type Value = A;
+
+    fn new_tree(&self, run: &mut TestRunner) -> NewTree<Self> {
+        self.strategy.new_tree(run)
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, feature(coverage_attribute))]
+mod tests {
+    use alloc::format;
+
+    use proptest::strategy::ValueTree;
+
+    use super::*;
+    use crate::arb;
+    use crate::arb_named;
+
+    fn first_value<S: Strategy>(strategy: S) -> S::Value {
+        strategy
+            .new_tree(&mut TestRunner::deterministic())
+            .unwrap()
+            .current()
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn arb_strategy_can_be_boxed() {
+        let boxed: BoxedStrategy<u64> = arb::<u64>().into();
+
+        assert_eq!(first_value(arb::<u64>()), first_value(boxed));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn arb_boxed_generates_like_the_original() {
+        let boxed = ArbBoxed::from(arb::<u64>());
+        assert_eq!(first_value(arb::<u64>()), first_value(boxed.clone()));
+        assert_eq!(first_value(arb::<u64>()), first_value(boxed.into_inner()));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn arb_boxed_shows_the_name() {
+        let boxed = ArbBoxed::from(arb_named::<u64>("nonce"));
+
+        assert_eq!("nonce", boxed.name());
+        assert_eq!("boxed nonce", format!("{boxed}"));
+        assert!(format!("{boxed:?}").contains("nonce"));
+    }
+}
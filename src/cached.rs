@@ -0,0 +1,153 @@
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use proptest::strategy::Strategy;
+use proptest::test_runner::TestRunner;
+
+use crate::ArbInterop;
+use crate::ArbStrategy;
+use crate::ArbValueTree;
+use crate::arb;
+use crate::lock;
+
+/// A [`proptest::strategy::Strategy`] like [`ArbStrategy`] that rejects random
+/// data it recently generated a value from, so that test cases do not repeat.
+///
+/// Mostly useful for small sizes of random data, like 1 to 16 bytes, where
+/// duplicates are common. Once (nearly) all possible random data has been seen,
+/// rejections pile up until proptest gives up, so keep the number of test cases
+/// below the number of possible byte sequences. See [`arb_cached`].
+///
+/// Clones of the strategy share the cache.
+#[derive(Clone, Debug)]
+pub struct CachedArbStrategy<A: ArbInterop> {
+    strategy: ArbStrategy<A>,
+    cache: Arc<Mutex<Cache>>,
+}
+
+/// The most recently seen random data, up to a maximum number.
+#[derive(Debug)]
+struct Cache {
+    capacity: usize,
+    seen: HashSet<Vec<u8>>,
+
+    /// The contents of `seen`, oldest first.
+    order: VecDeque<Vec<u8>>,
+}
+
+impl Cache {
+    /// Records the given random data as seen, evicting the oldest if needed.
+    /// Returns whether it had not been seen yet.
+    fn insert(&mut self, bytes: &[u8]) -> bool {
+        if self.capacity == 0 || self.seen.contains(bytes) {
+            return self.capacity == 0;
+        }
+
+        if self.order.len() == self.capacity
+            && let Some(oldest) = self.order.pop_front()
+        {
+            self.seen.remove(&oldest);
+        }
+        self.seen.insert(bytes.to_vec());
+        self.order.push_back(bytes.to_vec());
+
+        true
+    }
+}
+
+impl<A: ArbInterop> CachedArbStrategy<A> {
+    /// Generates values like the given `strategy`, rejecting random data that
+    /// is among the `cache_size` most recently seen.
+    pub fn new(strategy: ArbStrategy<A>, cache_size: usize) -> Self {
+        let cache = Cache {
+            capacity: cache_size,
+            seen: HashSet::new(),
+            order: VecDeque::new(),
+        };
+
+        Self {
+            strategy,
+            cache: Arc::new(Mutex::new(cache)),
+        }
+    }
+}
+
+impl<A: ArbInterop> Strategy for CachedArbStrategy<A> {
+    type Tree = ArbValueTree<A>;
+    type Value = A;
+
+    fn new_tree(&self, run: &mut TestRunner) -> proptest::strategy::NewTree<Self> {
+        loop {
+            let tree = self.strategy.new_tree(run)?;
+            let mut cache = lock(&self.cache);
+            if cache.insert(tree.raw_bytes()) {
+                return Ok(tree);
+            }
+            drop(cache);
+
+            run.reject_local(format!("{}: repeated random data", self.strategy.origin()))?;
+        }
+    }
+}
+
+/// Constructs a [`CachedArbStrategy`] for a given [`arbitrary::Arbitrary`]
+/// type, sized like [`arb`] does, that remembers the `cache_size` most
+/// recently seen byte sequences of random data.
+pub fn arb_cached<A: ArbInterop>(cache_size: usize) -> CachedArbStrategy<A> {
+    CachedArbStrategy::new(arb(), cache_size)
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, feature(coverage_attribute))]
+mod tests {
+    use proptest::strategy::ValueTree;
+
+    use super::*;
+
+    fn generate(strategy: &CachedArbStrategy<u8>, n: usize) -> Vec<u8> {
+        let mut runner = TestRunner::deterministic();
+        (0..n)
+            .map(|_| strategy.new_tree(&mut runner).unwrap().current())
+            .collect()
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn all_one_byte_values_are_distinct() {
+        let values = generate(&arb_cached::<u8>(256), 256);
+        let distinct = values.iter().collect::<HashSet<_>>();
+
+        assert_eq!(256, distinct.len());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn oldest_random_data_is_evicted() {
+        let values = generate(&arb_cached::<u8>(16), 1024);
+
+        for window in values.windows(16) {
+            assert_eq!(16, window.iter().collect::<HashSet<_>>().len());
+        }
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn empty_cache_allows_repetition() {
+        let values = generate(&arb_cached::<u8>(0), 1024);
+        let distinct = values.iter().collect::<HashSet<_>>();
+
+        assert!(distinct.len() < 1024);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn exhausted_random_data_fails() {
+        let strategy = arb_cached::<u8>(256);
+        generate(&strategy, 256);
+
+        let mut runner = TestRunner::deterministic();
+        assert!(strategy.new_tree(&mut runner).is_err());
+    }
+}
@@ -0,0 +1,109 @@
+use alloc::vec::Vec;
+
+use proptest::strategy::Strategy;
+use proptest::test_runner::Reason;
+use proptest::test_runner::TestRunner;
+
+use crate::ArbInterop;
+use crate::ArbStrategy;
+use crate::ArbValueTree;
+
+/// A [`proptest::strategy::Strategy`] that tries a list of [`ArbStrategy`]s in
+/// order, generating each value with the first one that succeeds. See
+/// [`arb_chain`].
+///
+/// Unlike [`proptest::prop_oneof!`], which picks a strategy at random, the
+/// first strategy is always tried first. A strategy gives up once it exceeds
+/// its [`max_retries`](crate::ArbConfigBuilder::max_retries), which serves as
+/// its budget of attempts.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct ChainArbStrategy<A: ArbInterop> {
+    strategies: Vec<ArbStrategy<A>>,
+}
+
+impl<A: ArbInterop> Strategy for ChainArbStrategy<A> {
+    type Tree = ArbValueTree<A>;
+    type Value = A;
+
+    fn new_tree(&self, run: &mut TestRunner) -> proptest::strategy::NewTree<Self> {
+        let mut last_reason = Reason::from("no strategies to chain");
+        for strategy in &self.strategies {
+            match strategy.new_tree(run) {
+                Ok(tree) => return Ok(tree),
+                Err(reason) => last_reason = reason,
+            }
+        }
+
+        Err(last_reason)
+    }
+}
+
+/// Constructs a [`ChainArbStrategy`], which generates values with the first of
+/// the given `strategies` that succeeds, for example preferring small random
+/// data but falling back to larger data if the small data is rejected too
+/// often.
+pub fn arb_chain<A: ArbInterop>(strategies: Vec<ArbStrategy<A>>) -> ChainArbStrategy<A> {
+    ChainArbStrategy { strategies }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, feature(coverage_attribute))]
+mod tests {
+    use alloc::vec;
+
+    use arbitrary::Arbitrary;
+
+    use super::*;
+    use crate::ArbConfig;
+    use crate::arb_sized;
+
+    /// Requires at least 8 bytes to be generated from.
+    #[derive(Debug, Clone)]
+    struct AtLeast8;
+
+    impl<'a> Arbitrary<'a> for AtLeast8 {
+        fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+            u.bytes(8)?;
+            Ok(Self)
+        }
+    }
+
+    fn sized(size: usize) -> ArbStrategy<AtLeast8> {
+        ArbStrategy::with_config(ArbConfig::builder().size(size).max_retries(2).build())
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn first_strategy_is_preferred() {
+        let strategy = arb_chain(vec![arb_sized::<u8>(1), arb_sized::<u8>(2)]);
+        let mut runner = TestRunner::deterministic();
+        for _ in 0..10 {
+            let tree = strategy.new_tree(&mut runner).unwrap();
+            assert_eq!(1, tree.raw_bytes().len());
+        }
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn later_strategies_are_fallbacks() {
+        let strategy = arb_chain(vec![sized(4), sized(6), sized(8), sized(16)]);
+        let tree = strategy.new_tree(&mut TestRunner::deterministic()).unwrap();
+
+        assert_eq!(8, tree.raw_bytes().len());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn chain_fails_if_all_strategies_fail() {
+        let strategy = arb_chain(vec![sized(4), sized(6)]);
+        let reason = strategy
+            .new_tree(&mut TestRunner::deterministic())
+            .unwrap_err();
+        assert!(reason.message().contains("too many rejections"));
+
+        let reason = arb_chain::<u8>(vec![])
+            .new_tree(&mut TestRunner::deterministic())
+            .unwrap_err();
+        assert_eq!("no strategies to chain", reason.message());
+    }
+}
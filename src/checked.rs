@@ -0,0 +1,187 @@
+use std::string::String;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use proptest::strategy::Strategy;
+use proptest::test_runner::TestRunner;
+
+use crate::ArbInterop;
+use crate::ArbStrategy;
+use crate::ArbValueTree;
+use crate::arb;
+use crate::lock;
+use crate::warn;
+
+/// A [`proptest::strategy::Strategy`] like [`ArbStrategy`] that checks the
+/// [`size_hint`](arbitrary::Arbitrary::size_hint) of the
+/// [`arbitrary::Arbitrary`] implementation against the bytes of random data it
+/// actually reads. See [`arb_checked`].
+///
+/// Generating a value that reads more bytes than the upper bound of the size
+/// hint panics. If every value generated reads fewer bytes than the lower
+/// bound, a warning is emitted once the strategy and all its clones are
+/// dropped: as a `tracing` event with the feature `tracing`, and on standard
+/// error otherwise. A development tool: checking rereads the random data of
+/// every value.
+///
+/// Clones of the strategy share the tracking.
+#[derive(Clone, Debug)]
+pub struct CheckedArbStrategy<A: ArbInterop> {
+    strategy: ArbStrategy<A>,
+    consumption: Arc<Mutex<Consumption>>,
+}
+
+/// How many of the values generated so far read fewer bytes than the lower
+/// bound of the size hint.
+#[derive(Debug)]
+struct Consumption {
+    origin: String,
+    lower_bound: usize,
+    num_values: usize,
+    num_below_lower_bound: usize,
+}
+
+impl<A: ArbInterop> CheckedArbStrategy<A> {
+    /// Generates values like the given `strategy`, checking the size hint of
+    /// `A`.
+    pub fn new(strategy: ArbStrategy<A>) -> Self {
+        let consumption = Consumption {
+            origin: strategy.origin(),
+            lower_bound: A::size_hint(0).0,
+            num_values: 0,
+            num_below_lower_bound: 0,
+        };
+
+        Self {
+            strategy,
+            consumption: Arc::new(Mutex::new(consumption)),
+        }
+    }
+}
+
+impl<A: ArbInterop> Strategy for CheckedArbStrategy<A> {
+    type Tree = ArbValueTree<A>;
+    type Value = A;
+
+    fn new_tree(&self, run: &mut TestRunner) -> proptest::strategy::NewTree<Self> {
+        let tree = self.strategy.new_tree(run)?;
+        let consumed = tree.bytes_consumed();
+
+        if let (_, Some(upper_bound)) = A::size_hint(0) {
+            assert!(
+                consumed <= upper_bound,
+                "{}: read {consumed} bytes, more than the upper bound {upper_bound} of the size \
+                 hint (random data: {:02x?})",
+                self.strategy.origin(),
+                tree.raw_bytes(),
+            );
+        }
+
+        let mut consumption = lock(&self.consumption);
+        consumption.num_values += 1;
+        if consumed < consumption.lower_bound {
+            consumption.num_below_lower_bound += 1;
+        }
+
+        Ok(tree)
+    }
+}
+
+impl Drop for Consumption {
+    fn drop(&mut self) {
+        if self.num_values > 0 && self.num_below_lower_bound == self.num_values {
+            warn(format_args!(
+                "{}: all {} values read fewer bytes than the lower bound {} of the size hint",
+                self.origin, self.num_values, self.lower_bound,
+            ));
+        }
+    }
+}
+
+/// Constructs a [`CheckedArbStrategy`] for a given [`arbitrary::Arbitrary`]
+/// type, sized like [`arb`] does, which checks the type's
+/// [`size_hint`](arbitrary::Arbitrary::size_hint).
+///
+/// ```
+/// # use proptest::strategy::Strategy;
+/// # use proptest::test_runner::TestRunner;
+/// # use proptest_arbitrary_adapter::arb_checked;
+/// let strategy = arb_checked::<(u8, u32)>();
+/// strategy.new_tree(&mut TestRunner::deterministic()).unwrap();
+/// ```
+pub fn arb_checked<A: ArbInterop>() -> CheckedArbStrategy<A> {
+    CheckedArbStrategy::new(arb())
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, feature(coverage_attribute))]
+mod tests {
+    use arbitrary::Arbitrary;
+    use arbitrary::Unstructured;
+
+    use super::*;
+
+    /// Reads one byte, but claims to read exactly two.
+    #[derive(Debug, Clone)]
+    struct Overstated;
+
+    impl<'a> Arbitrary<'a> for Overstated {
+        fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+            u8::arbitrary(u)?;
+            Ok(Self)
+        }
+
+        fn size_hint(_: usize) -> (usize, Option<usize>) {
+            (2, Some(2))
+        }
+    }
+
+    /// Reads four bytes, but claims to read at most one.
+    #[derive(Debug, Clone)]
+    struct Understated;
+
+    impl<'a> Arbitrary<'a> for Understated {
+        fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+            u32::arbitrary(u)?;
+            Ok(Self)
+        }
+
+        fn size_hint(_: usize) -> (usize, Option<usize>) {
+            (0, Some(1))
+        }
+    }
+
+    fn num_below_lower_bound<A: ArbInterop>(strategy: &CheckedArbStrategy<A>) -> usize {
+        let mut runner = TestRunner::deterministic();
+        for _ in 0..100 {
+            strategy.new_tree(&mut runner).unwrap();
+        }
+
+        let consumption = strategy.consumption.lock().unwrap();
+        assert_eq!(100, consumption.num_values);
+        consumption.num_below_lower_bound
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn accurate_size_hints_pass() {
+        assert_eq!(0, num_below_lower_bound(&arb_checked::<(u8, u32)>()));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn overstated_size_hints_are_detected() {
+        let strategy = CheckedArbStrategy::new(ArbStrategy::<Overstated>::new(8));
+
+        assert_eq!(100, num_below_lower_bound(&strategy.clone()));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[should_panic(expected = "read 4 bytes, more than the upper bound 1 of the size hint")]
+    fn understated_size_hints_panic() {
+        let strategy = CheckedArbStrategy::new(ArbStrategy::<Understated>::new(8));
+
+        strategy.new_tree(&mut TestRunner::deterministic()).unwrap();
+    }
+}
@@ -0,0 +1,246 @@
+use proptest::strategy::Strategy;
+use proptest::strategy::ValueTree;
+use proptest::test_runner::Reason;
+use proptest::test_runner::TestRunner;
+
+/// The number of bytes of random data used if nothing better is known.
+pub(crate) const DEFAULT_SIZE: usize = 256;
+
+/// How many bytes of random data an [`ArbStrategy`](crate::ArbStrategy) feeds
+/// to the [`arbitrary::Arbitrary`] implementation.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SizeMode {
+    /// Always use exactly this many bytes.
+    Fixed(usize),
+
+    /// For every test case, use a number of bytes sampled uniformly from the
+    /// inclusive range `[min, max]`.
+    Range(usize, usize),
+
+    /// For every test case, use either `base` or `recursive` many bytes. The
+    /// latter is chosen with a probability of `recursive_per_mille` / 1000.
+    Bimodal {
+        base: usize,
+        recursive: usize,
+        recursive_per_mille: u32,
+    },
+}
+
+impl SizeMode {
+    /// Applies `f` to all sizes. As long as `f` is monotonic, ranges stay
+    /// valid.
+    pub(crate) fn map_sizes(self, f: impl Fn(usize) -> usize) -> Self {
+        match self {
+            Self::Fixed(size) => Self::Fixed(f(size)),
+            Self::Range(min, max) => Self::Range(f(min), f(max)),
+            Self::Bimodal {
+                base,
+                recursive,
+                recursive_per_mille,
+            } => Self::Bimodal {
+                base: f(base),
+                recursive: f(recursive),
+                recursive_per_mille,
+            },
+        }
+    }
+
+    pub(crate) fn sample(&self, run: &mut TestRunner) -> Result<usize, Reason> {
+        match *self {
+            Self::Fixed(size) => Ok(size),
+            Self::Range(min, max) => Ok((min..=max).new_tree(run)?.current()),
+            Self::Bimodal {
+                base,
+                recursive,
+                recursive_per_mille,
+            } => {
+                let is_recursive = (0..1000_u32).new_tree(run)?.current() < recursive_per_mille;
+                Ok(if is_recursive { recursive } else { base })
+            }
+        }
+    }
+}
+
+impl Default for SizeMode {
+    fn default() -> Self {
+        Self::Fixed(DEFAULT_SIZE)
+    }
+}
+
+/// All the knobs to tune an [`ArbStrategy`](crate::ArbStrategy).
+///
+/// Use [`ArbConfig::builder`] to conveniently construct a configuration, and
+/// [`ArbStrategy::with_config`](crate::ArbStrategy::with_config) to use it.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct ArbConfig {
+    /// How many bytes of random data to feed to the [`arbitrary::Arbitrary`]
+    /// implementation.
+    pub size_mode: SizeMode,
+
+    /// The maximum number of times generation of a value is retried if the
+    /// [`arbitrary::Arbitrary`] implementation rejects the random data.
+    /// Exceeding it fails the test, which usually indicates a misconfigured
+    /// [`size_mode`](Self::size_mode).
+    pub max_retries: u32,
+
+    /// The maximum number of times a generated value is simplified.
+    pub shrink_max_steps: usize,
+
+    /// The number of test cases the strategy is meant for, if limited.
+    ///
+    /// This is advisory only: proptest has no notion of per-strategy limits, so
+    /// it is up to the user to respect it, for example with
+    /// [`Config::with_cases`](proptest::test_runner::Config::with_cases).
+    pub max_cases: Option<u32>,
+
+    /// Whether the [`Debug`] output of the generated value trees includes the
+    /// random data in hexadecimal. Always the case with the feature
+    /// `verbose-bytes`.
+    pub verbose_bytes: bool,
+}
+
+impl Default for ArbConfig {
+    fn default() -> Self {
+        Self {
+            size_mode: SizeMode::default(),
+            max_retries: 1000,
+            shrink_max_steps: usize::MAX,
+            max_cases: None,
+            verbose_bytes: false,
+        }
+    }
+}
+
+impl ArbConfig {
+    /// Starts building a configuration from the default one.
+    pub fn builder() -> ArbConfigBuilder {
+        ArbConfigBuilder::default()
+    }
+}
+
+/// Builds an [`ArbConfig`], starting from its [default](ArbConfig::default).
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct ArbConfigBuilder {
+    config: ArbConfig,
+}
+
+impl ArbConfigBuilder {
+    /// Always feed exactly `size` bytes to the [`arbitrary::Arbitrary`]
+    /// implementation.
+    pub fn size(self, size: usize) -> Self {
+        self.size_mode(SizeMode::Fixed(size))
+    }
+
+    /// For every test case, feed a number of bytes sampled uniformly from the
+    /// inclusive range `[min, max]` to the [`arbitrary::Arbitrary`]
+    /// implementation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min` is greater than `max`.
+    pub fn size_range(self, min: usize, max: usize) -> Self {
+        assert!(min <= max, "invalid size range: {min} > {max}");
+
+        self.size_mode(SizeMode::Range(min, max))
+    }
+
+    /// Sets the [`size_mode`](ArbConfig::size_mode).
+    pub fn size_mode(mut self, size_mode: SizeMode) -> Self {
+        self.config.size_mode = size_mode;
+        self
+    }
+
+    /// Sets the [`max_retries`](ArbConfig::max_retries).
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.config.max_retries = max_retries;
+        self
+    }
+
+    /// Sets the [`shrink_max_steps`](ArbConfig::shrink_max_steps).
+    pub fn shrink_max_steps(mut self, shrink_max_steps: usize) -> Self {
+        self.config.shrink_max_steps = shrink_max_steps;
+        self
+    }
+
+    /// Sets the [`max_cases`](ArbConfig::max_cases).
+    pub fn max_cases(mut self, max_cases: u32) -> Self {
+        self.config.max_cases = Some(max_cases);
+        self
+    }
+
+    /// Sets [`verbose_bytes`](ArbConfig::verbose_bytes), which includes the
+    /// random data in [`Debug`] output.
+    pub fn verbose_bytes(mut self, verbose_bytes: bool) -> Self {
+        self.config.verbose_bytes = verbose_bytes;
+        self
+    }
+
+    /// Finishes building the configuration.
+    pub fn build(self) -> ArbConfig {
+        self.config
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, feature(coverage_attribute))]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn builder_starts_from_default() {
+        assert_eq!(ArbConfig::default(), ArbConfig::builder().build());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn builder_sets_all_knobs() {
+        let config = ArbConfig::builder()
+            .size_range(10, 20)
+            .max_retries(3)
+            .shrink_max_steps(5)
+            .max_cases(7)
+            .verbose_bytes(true)
+            .build();
+        let expected = ArbConfig {
+            size_mode: SizeMode::Range(10, 20),
+            max_retries: 3,
+            shrink_max_steps: 5,
+            max_cases: Some(7),
+            verbose_bytes: true,
+        };
+
+        assert_eq!(expected, config);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn all_sizes_are_mapped() {
+        let double = |size| 2 * size;
+        assert_eq!(SizeMode::Fixed(8), SizeMode::Fixed(4).map_sizes(double));
+        assert_eq!(
+            SizeMode::Range(2, 8),
+            SizeMode::Range(1, 4).map_sizes(double)
+        );
+
+        let bimodal = SizeMode::Bimodal {
+            base: 1,
+            recursive: 4,
+            recursive_per_mille: 500,
+        };
+        let expected = SizeMode::Bimodal {
+            base: 2,
+            recursive: 8,
+            recursive_per_mille: 500,
+        };
+        assert_eq!(expected, bimodal.map_sizes(double));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[should_panic]
+    fn inverted_size_range_is_rejected() {
+        let _ = ArbConfig::builder().size_range(20, 10);
+    }
+}
@@ -0,0 +1,169 @@
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+
+use proptest::strategy::Strategy;
+use proptest::test_runner::Reason;
+use proptest::test_runner::TestRunner;
+
+use crate::ArbInterop;
+use crate::ArbStrategy;
+use crate::ArbValueTree;
+use crate::arb;
+use crate::random_below;
+
+/// A [`proptest::strategy::Strategy`] that generates values from the files of
+/// a corpus, for example one accumulated by a fuzzing campaign.
+///
+/// For every test case, the bytes of one randomly chosen corpus file are fed
+/// to the [`arbitrary::Arbitrary`] implementation. The generated values are
+/// shrunk as usual. If the corpus is empty, random data is generated instead,
+/// like [`arb`] does.
+///
+/// Like [`arb`], gives up after the default number of
+/// [`max_retries`](crate::ArbConfig::max_retries) rejected corpus files.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct CorpusArbStrategy<A: ArbInterop> {
+    /// The paths and contents of the corpus files.
+    corpus: Vec<(PathBuf, Vec<u8>)>,
+
+    /// Generates values if the corpus is empty. Also names the strategy in
+    /// failure messages, limits the number of rejections and configures
+    /// shrinking.
+    fallback: ArbStrategy<A>,
+}
+
+impl<A: ArbInterop> CorpusArbStrategy<A> {
+    /// Loads all files in the given directory as the corpus. Subdirectories
+    /// are ignored.
+    pub fn from_dir(path: &Path) -> Result<Self, io::Error> {
+        let mut paths = std::fs::read_dir(path)?
+            .map(|entry| entry.map(|e| e.path()))
+            .collect::<Result<Vec<_>, _>>()?;
+        paths.retain(|path| path.is_file());
+
+        // Sort for a deterministic mapping from random numbers to corpus files.
+        paths.sort();
+
+        let corpus = paths
+            .into_iter()
+            .map(|path| std::fs::read(&path).map(|bytes| (path, bytes)))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            corpus,
+            fallback: arb(),
+        })
+    }
+
+    /// Describes an error of the [`arbitrary::Arbitrary`] implementation other
+    /// than a rejection, naming the corpus file with the given contents.
+    fn fatal_error(&self, bytes: &[u8], e: arbitrary::Error) -> Reason {
+        let origin = self.fallback.origin();
+        match self.corpus.iter().find(|(_, contents)| contents == bytes) {
+            Some((path, _)) => format!("{origin}: corpus file {}: {e}", path.display()).into(),
+            None => format!("{origin}: {e}").into(),
+        }
+    }
+}
+
+impl<A: ArbInterop> Strategy for CorpusArbStrategy<A> {
+    type Tree = ArbValueTree<A>;
+    type Value = A;
+
+    fn new_tree(&self, run: &mut TestRunner) -> proptest::strategy::NewTree<Self> {
+        if self.corpus.is_empty() {
+            return self.fallback.new_tree(run);
+        }
+
+        // The sizes are unused: the contents of a corpus file replace the
+        // random data. Errors other than rejections name the corpus file.
+        let generated = self.fallback.generate_sized(
+            run,
+            |_, _| Ok(0),
+            |run, bytes| bytes.clone_from(&self.corpus[random_below(run, self.corpus.len())].1),
+            || (),
+            |bytes| match ArbValueTree::try_from(arbitrary::Unstructured::new(&bytes)) {
+                Err(e @ (arbitrary::Error::IncorrectFormat | arbitrary::Error::NotEnoughData)) => {
+                    Err(e)
+                }
+                built => Ok(built.map_err(|e| self.fatal_error(&bytes, e))),
+            },
+        )?;
+        let mut tree = generated?;
+        self.fallback.configure_tree(&mut tree);
+
+        Ok(tree)
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, feature(coverage_attribute))]
+mod tests {
+    // Not run on `wasm32`, which has no file system.
+
+    use std::path::PathBuf;
+
+    use proptest::prelude::*;
+    use test_strategy::proptest;
+
+    use super::*;
+
+    #[derive(Debug, Clone, arbitrary::Arbitrary)]
+    struct Test(u8);
+
+    /// Creates a fresh directory containing one file per given corpus entry.
+    fn corpus_dir(name: &str, entries: &[&[u8]]) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        for (i, entry) in entries.iter().enumerate() {
+            std::fs::write(dir.join(i.to_string()), entry).unwrap();
+        }
+
+        dir
+    }
+
+    #[proptest]
+    fn values_are_generated_from_corpus(
+        #[strategy(CorpusArbStrategy::from_dir(&corpus_dir("corpus", &[&[1], &[2, 3]])).unwrap())]
+        test: Test,
+    ) {
+        prop_assert!([1, 2].contains(&test.0));
+    }
+
+    #[proptest(cases = 10)]
+    fn empty_corpus_falls_back_to_random_generation(
+        #[strategy(CorpusArbStrategy::from_dir(&corpus_dir("empty-corpus", &[])).unwrap())]
+        _test: Test,
+    ) {
+    }
+
+    #[test]
+    fn too_many_rejections_fail() {
+        #[derive(Debug, Clone)]
+        struct Never;
+
+        impl<'a> arbitrary::Arbitrary<'a> for Never {
+            fn arbitrary(_: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+                Err(arbitrary::Error::IncorrectFormat)
+            }
+        }
+
+        let dir = corpus_dir("rejected-corpus", &[&[1]]);
+        let strategy = CorpusArbStrategy::<Never>::from_dir(&dir).unwrap();
+        let reason = strategy
+            .new_tree(&mut TestRunner::deterministic())
+            .unwrap_err();
+
+        let expected = format!("{}: too many rejections", core::any::type_name::<Never>());
+        assert!(reason.message().starts_with(&expected));
+    }
+
+    #[test]
+    fn missing_directory_is_an_error() {
+        let path = std::env::temp_dir().join("this-corpus-does-not-exist");
+
+        assert!(CorpusArbStrategy::<Test>::from_dir(&path).is_err());
+    }
+}
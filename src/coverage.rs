@@ -0,0 +1,195 @@
+use std::collections::HashSet;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use proptest::strategy::Strategy;
+use proptest::test_runner::TestRunner;
+
+use crate::ArbInterop;
+use crate::ArbStrategy;
+use crate::ArbValueTree;
+use crate::arb;
+use crate::lock;
+use crate::warn;
+
+/// A [`proptest::strategy::Strategy`] like [`ArbStrategy`] that tracks which
+/// variants of an enum it generated, and warns if not all of them came up
+/// within a number of test cases: as a `tracing` event with the feature
+/// `tracing`, and on standard error otherwise. See [`arb_coverage`].
+///
+/// Variants are told apart by a user-provided discriminant function, so any
+/// classification of values works, not just enum variants.
+///
+/// Clones of the strategy share the tracking.
+#[derive(Clone)]
+pub struct CoverageArbStrategy<A: ArbInterop, F> {
+    strategy: ArbStrategy<A>,
+    discriminant: F,
+    expected_variants: usize,
+    max_cases: usize,
+    coverage: Arc<Mutex<Coverage>>,
+}
+
+/// The variants seen so far.
+#[derive(Debug, Default)]
+struct Coverage {
+    seen: HashSet<usize>,
+    num_cases: usize,
+}
+
+impl<A: ArbInterop, F: Fn(&A) -> usize> CoverageArbStrategy<A, F> {
+    /// Generates values like the given `strategy`, warning if fewer than
+    /// `expected_variants` distinct results of `discriminant` came up within
+    /// the first `max_cases` values.
+    pub fn new(
+        strategy: ArbStrategy<A>,
+        expected_variants: usize,
+        discriminant: F,
+        max_cases: usize,
+    ) -> Self {
+        Self {
+            strategy,
+            discriminant,
+            expected_variants,
+            max_cases,
+            coverage: Arc::default(),
+        }
+    }
+
+    /// The number of distinct variants generated so far.
+    pub fn num_seen_variants(&self) -> usize {
+        lock(&self.coverage).seen.len()
+    }
+
+    /// Whether all expected variants were generated so far.
+    pub fn is_covered(&self) -> bool {
+        self.num_seen_variants() >= self.expected_variants
+    }
+}
+
+impl<A: ArbInterop, F> Debug for CoverageArbStrategy<A, F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CoverageArbStrategy")
+            .field("strategy", &self.strategy)
+            .field("expected_variants", &self.expected_variants)
+            .field("max_cases", &self.max_cases)
+            .field("coverage", &self.coverage)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<A: ArbInterop, F: Fn(&A) -> usize> Strategy for CoverageArbStrategy<A, F> {
+    type Tree = ArbValueTree<A>;
+    type Value = A;
+
+    fn new_tree(&self, run: &mut TestRunner) -> proptest::strategy::NewTree<Self> {
+        let tree = self.strategy.new_tree(run)?;
+
+        let mut coverage = lock(&self.coverage);
+        coverage.seen.insert((self.discriminant)(&tree.curr));
+        coverage.num_cases += 1;
+        if coverage.num_cases == self.max_cases && coverage.seen.len() < self.expected_variants {
+            warn(format_args!(
+                "{}: only {} of {} variants covered after {} test cases",
+                self.strategy.origin(),
+                coverage.seen.len(),
+                self.expected_variants,
+                self.max_cases,
+            ));
+        }
+
+        Ok(tree)
+    }
+}
+
+/// Constructs a [`CoverageArbStrategy`] for a given [`arbitrary::Arbitrary`]
+/// type, sized like [`arb`] does, which warns if fewer than `expected_variants`
+/// distinct results of `discriminant` came up within `max_cases` test cases.
+///
+/// ```
+/// # use proptest::strategy::Strategy;
+/// # use proptest::test_runner::TestRunner;
+/// # use proptest_arbitrary_adapter::arb_coverage;
+/// let strategy = arb_coverage::<bool, _>(2, |b| usize::from(*b), 100);
+/// let mut runner = TestRunner::deterministic();
+/// for _ in 0..100 {
+///     strategy.new_tree(&mut runner).unwrap();
+/// }
+///
+/// assert!(strategy.is_covered());
+/// ```
+pub fn arb_coverage<A, F>(
+    expected_variants: usize,
+    discriminant: F,
+    max_cases: usize,
+) -> CoverageArbStrategy<A, F>
+where
+    A: ArbInterop,
+    F: Fn(&A) -> usize,
+{
+    CoverageArbStrategy::new(arb(), expected_variants, discriminant, max_cases)
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, feature(coverage_attribute))]
+mod tests {
+    use arbitrary::Arbitrary;
+
+    use super::*;
+    use crate::arb_sized;
+
+    #[derive(Debug, Clone, Arbitrary)]
+    enum Shape {
+        Circle,
+        Square,
+        Triangle,
+    }
+
+    fn discriminant(shape: &Shape) -> usize {
+        match shape {
+            Shape::Circle => 0,
+            Shape::Square => 1,
+            Shape::Triangle => 2,
+        }
+    }
+
+    fn generate<F: Fn(&Shape) -> usize>(strategy: &CoverageArbStrategy<Shape, F>, n: usize) {
+        let mut runner = TestRunner::deterministic();
+        for _ in 0..n {
+            strategy.new_tree(&mut runner).unwrap();
+        }
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn all_variants_are_covered() {
+        let strategy = arb_coverage::<Shape, _>(3, discriminant, 100);
+        assert!(!strategy.is_covered());
+
+        generate(&strategy, 100);
+        assert_eq!(3, strategy.num_seen_variants());
+        assert!(strategy.is_covered());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn missing_variants_are_detected() {
+        // Zero bytes always yield the first variant.
+        let strategy = CoverageArbStrategy::new(arb_sized::<Shape>(0), 3, discriminant, 100);
+        generate(&strategy, 100);
+
+        assert_eq!(1, strategy.num_seen_variants());
+        assert!(!strategy.is_covered());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn clones_share_the_tracking() {
+        let strategy = arb_coverage::<Shape, _>(3, discriminant, 100);
+        generate(&strategy.clone(), 100);
+
+        assert!(strategy.is_covered());
+    }
+}
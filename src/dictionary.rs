@@ -0,0 +1,150 @@
+use alloc::vec::Vec;
+
+use proptest::prelude::RngCore;
+use proptest::strategy::Strategy;
+use proptest::strategy::ValueTree;
+use proptest::test_runner::TestRunner;
+
+use crate::ArbInterop;
+use crate::ArbStrategy;
+use crate::ArbValueTree;
+use crate::arb;
+use crate::assert_probability;
+use crate::random_below;
+
+/// A [`proptest::strategy::Strategy`] that splices entries of a user-supplied
+/// dictionary into the random data fed to the [`arbitrary::Arbitrary`]
+/// implementation.
+///
+/// Like the dictionaries of fuzzers, the entries can be magic numbers, format
+/// delimiters, protocol tokens, or anything else that is unlikely to come up in
+/// random data but guides generation towards interesting values. See
+/// [`arb_with_dictionary`].
+#[derive(Clone, PartialEq, PartialOrd, Debug)]
+pub struct DictionaryArbStrategy<A: ArbInterop> {
+    strategy: ArbStrategy<A>,
+    dictionary: Vec<Vec<u8>>,
+
+    /// The probability with which a dictionary entry is spliced into the random
+    /// data of any test case.
+    splice_probability: f64,
+}
+
+impl<A: ArbInterop> DictionaryArbStrategy<A> {
+    /// Splices entries of the `dictionary` into the random data of the given
+    /// `strategy`: with the given `splice_probability`, a random entry
+    /// overwrites the random data at a random offset.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `splice_probability` is not in the range `[0.0, 1.0]`.
+    pub fn new(
+        strategy: ArbStrategy<A>,
+        dictionary: Vec<Vec<u8>>,
+        splice_probability: f64,
+    ) -> Self {
+        assert_probability(splice_probability);
+
+        Self {
+            strategy,
+            dictionary,
+            splice_probability,
+        }
+    }
+
+    fn fill_bytes(&self, run: &mut TestRunner, bytes: &mut [u8]) {
+        run.rng().fill_bytes(bytes);
+        if self.dictionary.is_empty() || bytes.is_empty() {
+            return;
+        }
+
+        let is_splice_due = proptest::bool::weighted(self.splice_probability)
+            .new_tree(run)
+            .is_ok_and(|tree| tree.current());
+        if !is_splice_due {
+            return;
+        }
+
+        // Entries that do not fit are cut off at the end of the random data.
+        let entry = &self.dictionary[random_below(run, self.dictionary.len())];
+        let offset = random_below(run, bytes.len());
+        let len = entry.len().min(bytes.len() - offset);
+        bytes[offset..offset + len].copy_from_slice(&entry[..len]);
+    }
+}
+
+impl<A: ArbInterop> Strategy for DictionaryArbStrategy<A> {
+    type Tree = ArbValueTree<A>;
+    type Value = A;
+
+    fn new_tree(&self, run: &mut TestRunner) -> proptest::strategy::NewTree<Self> {
+        self.strategy
+            .new_tree_with(run, |run, bytes| self.fill_bytes(run, bytes))
+    }
+}
+
+/// Constructs a [`DictionaryArbStrategy`] for a given [`arbitrary::Arbitrary`]
+/// type, sized like [`arb`] does. With probability `splice_probability`, a
+/// random entry of the `dictionary` is spliced into the random data.
+///
+/// # Panics
+///
+/// Panics if `splice_probability` is not in the range `[0.0, 1.0]`.
+pub fn arb_with_dictionary<A: ArbInterop>(
+    dictionary: Vec<Vec<u8>>,
+    splice_probability: f64,
+) -> DictionaryArbStrategy<A> {
+    DictionaryArbStrategy::new(arb(), dictionary, splice_probability)
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, feature(coverage_attribute))]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+    use crate::arb_sized;
+
+    #[derive(Debug, Clone, arbitrary::Arbitrary)]
+    struct Bytes([u8; 64]);
+
+    const MAGIC: [u8; 4] = *b"\x7fELF";
+
+    fn num_with_magic(strategy: DictionaryArbStrategy<Bytes>) -> usize {
+        let mut runner = TestRunner::deterministic();
+        (0..100)
+            .map(|_| strategy.new_tree(&mut runner).unwrap().current())
+            .filter(|bytes| bytes.0.windows(4).any(|window| window == MAGIC))
+            .count()
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn dictionary_entries_are_spliced_in() {
+        assert_eq!(
+            0,
+            num_with_magic(arb_with_dictionary(vec![MAGIC.to_vec()], 0.0))
+        );
+        assert!(num_with_magic(arb_with_dictionary(vec![MAGIC.to_vec()], 0.5)) > 20);
+
+        // Entries spliced in too close to the end are cut off.
+        assert!(num_with_magic(arb_with_dictionary(vec![MAGIC.to_vec()], 1.0)) > 80);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn empty_dictionary_is_harmless() {
+        assert_eq!(0, num_with_magic(arb_with_dictionary(vec![], 1.0)));
+
+        let strategy = DictionaryArbStrategy::<u8>::new(arb_sized(0), vec![MAGIC.to_vec()], 1.0);
+        let tree = strategy.new_tree(&mut TestRunner::deterministic()).unwrap();
+        assert_eq!(0, tree.current_size());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[should_panic]
+    fn probability_must_not_exceed_one() {
+        let _ = arb_with_dictionary::<Bytes>(vec![], 1.5);
+    }
+}
@@ -0,0 +1,131 @@
+use alloc::vec::Vec;
+use core::fmt::Debug;
+use core::fmt::Formatter;
+
+use proptest::strategy::Strategy;
+use proptest::test_runner::TestRunner;
+
+use crate::ArbInterop;
+use crate::ArbStrategy;
+use crate::ArbValueTree;
+use crate::arb;
+
+/// A [`proptest::strategy::Strategy`] that takes its random data from a
+/// user-provided source instead of the [`TestRunner`]'s random number
+/// generator, for example a hardware random number generator, a recorded
+/// network trace, or a deterministic test oracle. See [`arb_with_entropy`].
+///
+/// The source is called once per attempt to generate a value. If it returns
+/// fewer bytes than the size the strategy samples, the rest is zero; if it
+/// returns more, all of them are used. Values shrink as usual.
+#[derive(Clone)]
+pub struct EntropyArbStrategy<A: ArbInterop, F> {
+    strategy: ArbStrategy<A>,
+    entropy_fn: F,
+}
+
+impl<A: ArbInterop, F: Fn() -> Vec<u8>> EntropyArbStrategy<A, F> {
+    /// Feeds the random data `entropy_fn` returns to the given `strategy`.
+    pub fn new(strategy: ArbStrategy<A>, entropy_fn: F) -> Self {
+        Self {
+            strategy,
+            entropy_fn,
+        }
+    }
+}
+
+impl<A: ArbInterop, F> Debug for EntropyArbStrategy<A, F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("EntropyArbStrategy")
+            .field("strategy", &self.strategy)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<A: ArbInterop, F: Fn() -> Vec<u8>> Strategy for EntropyArbStrategy<A, F> {
+    type Tree = ArbValueTree<A>;
+    type Value = A;
+
+    fn new_tree(&self, run: &mut TestRunner) -> proptest::strategy::NewTree<Self> {
+        self.strategy.new_tree_with(run, |_, bytes| {
+            let size = bytes.len();
+            *bytes = (self.entropy_fn)();
+            if bytes.len() < size {
+                bytes.resize(size, 0);
+            }
+        })
+    }
+}
+
+/// Constructs an [`EntropyArbStrategy`] for a given [`arbitrary::Arbitrary`]
+/// type, sized like [`arb`] does, whose random data `entropy_fn` returns.
+pub fn arb_with_entropy<A, F>(entropy_fn: F) -> EntropyArbStrategy<A, F>
+where
+    A: ArbInterop,
+    F: Fn() -> Vec<u8>,
+{
+    EntropyArbStrategy::new(arb(), entropy_fn)
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, feature(coverage_attribute))]
+mod tests {
+    use alloc::vec;
+    use core::sync::atomic::AtomicU8;
+    use core::sync::atomic::Ordering;
+
+    use proptest::strategy::ValueTree;
+
+    use super::*;
+    use crate::arb_sized;
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn random_data_comes_from_entropy_source() {
+        let strategy = arb_with_entropy::<u32, _>(|| vec![1, 2, 3, 4]);
+        let tree = strategy.new_tree(&mut TestRunner::deterministic()).unwrap();
+
+        assert_eq!(u32::from_le_bytes([1, 2, 3, 4]), tree.current());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn short_random_data_is_padded_with_zeros() {
+        let strategy = EntropyArbStrategy::new(arb_sized::<u32>(8), || vec![7]);
+        let tree = strategy.new_tree(&mut TestRunner::deterministic()).unwrap();
+
+        assert_eq!([7, 0, 0, 0, 0, 0, 0, 0], tree.raw_bytes());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn long_random_data_is_kept() {
+        let strategy = EntropyArbStrategy::new(arb_sized::<u8>(1), || vec![7; 16]);
+        let tree = strategy.new_tree(&mut TestRunner::deterministic()).unwrap();
+
+        assert_eq!(16, tree.raw_bytes().len());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn entropy_source_is_called_per_test_case() {
+        let counter = AtomicU8::new(0);
+        let strategy = arb_with_entropy::<u8, _>(|| vec![counter.fetch_add(1, Ordering::Relaxed)]);
+        let mut runner = TestRunner::deterministic();
+        let values = (0..3)
+            .map(|_| strategy.new_tree(&mut runner).unwrap().current())
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![0, 1, 2], values);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn values_shrink_as_usual() {
+        let strategy = arb_with_entropy::<u8, _>(|| vec![200]);
+        let mut tree = strategy.new_tree(&mut TestRunner::deterministic()).unwrap();
+        while tree.simplify() {}
+
+        assert_eq!(0, tree.current());
+    }
+}
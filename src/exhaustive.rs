@@ -0,0 +1,178 @@
+use alloc::format;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use core::sync::atomic::AtomicU64;
+use core::sync::atomic::Ordering;
+
+use proptest::strategy::Strategy;
+use proptest::test_runner::TestRunner;
+
+use crate::ArbInterop;
+use crate::ArbValueTree;
+
+/// The largest size [`ExhaustiveArbStrategy::up_to`] accepts.
+const MAX_SIZE: usize = 8;
+
+/// A [`proptest::strategy::Strategy`] that feeds every possible byte sequence
+/// up to some length to the [`arbitrary::Arbitrary`] implementation, one per
+/// test case, instead of random data.
+///
+/// Useful to check that an [`arbitrary::Arbitrary`] implementation is total.
+/// The sequences are enumerated shortest first, and in lexicographic order
+/// among equally long ones. After the last one, enumeration starts over. See
+/// [`ExhaustiveArbStrategy::up_to`].
+///
+/// Since it is meant to find them, the strategy fails on any rejected
+/// sequence. Clones of the strategy share the enumeration.
+#[derive(Clone, Debug)]
+pub struct ExhaustiveArbStrategy<A: ArbInterop> {
+    max_size: usize,
+
+    /// The index of the next byte sequence to enumerate.
+    next_index: Arc<AtomicU64>,
+    _ph: PhantomData<fn() -> A>,
+}
+
+impl<A: ArbInterop> ExhaustiveArbStrategy<A> {
+    /// Enumerates all byte sequences of length at most `max_size`. There are
+    /// more than `256^max_size` of them, so keep `max_size` small and the
+    /// number of test cases high.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_size` is greater than 8.
+    pub fn up_to(max_size: usize) -> Self {
+        assert!(
+            max_size <= MAX_SIZE,
+            "maximum size {max_size} exceeds {MAX_SIZE}"
+        );
+
+        Self {
+            max_size,
+            next_index: Arc::default(),
+            _ph: PhantomData,
+        }
+    }
+
+    /// The byte sequence with the given index in the enumeration.
+    fn bytes_at(&self, index: u64) -> Vec<u8> {
+        let mut index = u128::from(index);
+        let mut len = 0;
+        loop {
+            let num_of_len = 1_u128 << (8 * len);
+            if len == self.max_size || index < num_of_len {
+                break;
+            }
+            index -= num_of_len;
+            len += 1;
+        }
+
+        // Within sequences of equal length, the index is a big-endian number.
+        index.to_be_bytes()[16 - len..].to_vec()
+    }
+
+    /// The number of byte sequences in the enumeration.
+    fn num_sequences(&self) -> u128 {
+        (0..=self.max_size).map(|len| 1_u128 << (8 * len)).sum()
+    }
+}
+
+impl<A: ArbInterop> Strategy for ExhaustiveArbStrategy<A> {
+    type Tree = ArbValueTree<A>;
+    type Value = A;
+
+    fn new_tree(&self, _: &mut TestRunner) -> proptest::strategy::NewTree<Self> {
+        let index = self.next_index.fetch_add(1, Ordering::Relaxed);
+        let index = u128::from(index) % self.num_sequences();
+        let bytes = self.bytes_at(u64::try_from(index).unwrap_or(u64::MAX));
+
+        ArbValueTree::new(bytes.clone()).map_err(|e| format!("{e} for bytes {bytes:?}").into())
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, feature(coverage_attribute))]
+mod tests {
+    use alloc::vec;
+
+    use arbitrary::Arbitrary;
+    use proptest::strategy::ValueTree;
+
+    use super::*;
+    use crate::test_util::Bytes;
+
+    /// Rejects empty random data.
+    #[derive(Debug, Clone)]
+    struct NonEmpty;
+
+    impl<'a> Arbitrary<'a> for NonEmpty {
+        fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+            if u.is_empty() {
+                return Err(arbitrary::Error::NotEnoughData);
+            }
+
+            Ok(Self)
+        }
+    }
+
+    fn enumerate(strategy: &ExhaustiveArbStrategy<Bytes>, n: usize) -> Vec<Vec<u8>> {
+        let mut runner = TestRunner::deterministic();
+        (0..n)
+            .map(|_| strategy.new_tree(&mut runner).unwrap().current().0)
+            .collect()
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn all_sequences_are_enumerated_in_order() {
+        let sequences = enumerate(&ExhaustiveArbStrategy::up_to(1), 258);
+
+        assert_eq!(Vec::<u8>::new(), sequences[0]);
+        for byte in 0..=u8::MAX {
+            assert_eq!(vec![byte], sequences[usize::from(byte) + 1]);
+        }
+        assert_eq!(Vec::<u8>::new(), sequences[257]);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn longer_sequences_are_big_endian_numbers() {
+        let sequences = enumerate(&ExhaustiveArbStrategy::up_to(2), 1 + 256 + 258);
+
+        assert_eq!(vec![0, 0], sequences[257]);
+        assert_eq!(vec![0, 1], sequences[258]);
+        assert_eq!(vec![1, 0], sequences[257 + 256]);
+        assert_eq!(vec![1, 1], sequences[257 + 257]);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn largest_size_is_supported() {
+        let strategy = ExhaustiveArbStrategy::<Bytes>::up_to(8);
+        let num_shorter = (0..8).map(|len| 1_u64 << (8 * len)).sum::<u64>();
+
+        assert_eq!(vec![0xff; 7], strategy.bytes_at(num_shorter - 1));
+        assert_eq!(vec![0; 8], strategy.bytes_at(num_shorter));
+        assert_eq!(8, strategy.bytes_at(u64::MAX).len());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn rejected_sequences_fail() {
+        let strategy = ExhaustiveArbStrategy::<u8>::up_to(0);
+        assert!(strategy.new_tree(&mut TestRunner::deterministic()).is_ok());
+
+        let strategy = ExhaustiveArbStrategy::<NonEmpty>::up_to(1);
+        let mut runner = TestRunner::deterministic();
+        assert!(strategy.new_tree(&mut runner).is_err());
+        assert!(strategy.new_tree(&mut runner).is_ok());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[should_panic]
+    fn maximum_size_must_be_small() {
+        let _ = ExhaustiveArbStrategy::<u8>::up_to(9);
+    }
+}
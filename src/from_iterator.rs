@@ -0,0 +1,147 @@
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use proptest::prelude::RngCore;
+use proptest::strategy::Strategy;
+use proptest::test_runner::TestRunner;
+
+use crate::ArbInterop;
+use crate::ArbStrategy;
+use crate::ArbValueTree;
+use crate::arb;
+use crate::lock;
+
+/// A [`proptest::strategy::Strategy`] that takes its random data from an
+/// iterator of bytes, for example a cryptographic test vector or a recorded
+/// network capture. See [`arb_from_iterator`].
+///
+/// Each attempt to generate a value takes as many bytes from the iterator as
+/// the strategy samples. Once the iterator is exhausted, the
+/// [`TestRunner`]'s random number generator provides the rest. Values shrink
+/// as usual.
+///
+/// Clones of the strategy share the iterator.
+pub struct IteratorArbStrategy<A: ArbInterop, I> {
+    strategy: ArbStrategy<A>,
+    iter: Arc<Mutex<I>>,
+}
+
+impl<A: ArbInterop, I: Iterator<Item = u8>> IteratorArbStrategy<A, I> {
+    /// Feeds the bytes of `iter` to the given `strategy`.
+    pub fn new(strategy: ArbStrategy<A>, iter: I) -> Self {
+        Self {
+            strategy,
+            iter: Arc::new(Mutex::new(iter)),
+        }
+    }
+}
+
+impl<A: ArbInterop, I> Clone for IteratorArbStrategy<A, I> {
+    fn clone(&self) -> Self {
+        Self {
+            strategy: self.strategy.clone(),
+            iter: Arc::clone(&self.iter),
+        }
+    }
+}
+
+impl<A: ArbInterop, I> Debug for IteratorArbStrategy<A, I> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IteratorArbStrategy")
+            .field("strategy", &self.strategy)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<A: ArbInterop, I: Iterator<Item = u8>> Strategy for IteratorArbStrategy<A, I> {
+    type Tree = ArbValueTree<A>;
+    type Value = A;
+
+    fn new_tree(&self, run: &mut TestRunner) -> proptest::strategy::NewTree<Self> {
+        self.strategy.new_tree_with(run, |run, bytes| {
+            let mut iter = lock(&self.iter);
+            let mut num_taken = 0;
+            for (byte, taken) in bytes.iter_mut().zip(&mut *iter) {
+                *byte = taken;
+                num_taken += 1;
+            }
+            run.rng().fill_bytes(&mut bytes[num_taken..]);
+        })
+    }
+}
+
+/// Constructs an [`IteratorArbStrategy`] for a given [`arbitrary::Arbitrary`]
+/// type, sized like [`arb`] does, whose random data comes from `iter` until it
+/// is exhausted.
+///
+/// ```
+/// # use proptest::strategy::Strategy;
+/// # use proptest::strategy::ValueTree;
+/// # use proptest::test_runner::TestRunner;
+/// # use proptest_arbitrary_adapter::arb_from_iterator;
+/// let strategy = arb_from_iterator::<u16, _>([0x34, 0x12]);
+/// let tree = strategy.new_tree(&mut TestRunner::deterministic()).unwrap();
+/// assert_eq!(0x1234, tree.current());
+/// ```
+pub fn arb_from_iterator<A, I>(iter: I) -> IteratorArbStrategy<A, I::IntoIter>
+where
+    A: ArbInterop,
+    I: IntoIterator<Item = u8>,
+{
+    IteratorArbStrategy::new(arb(), iter.into_iter())
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, feature(coverage_attribute))]
+mod tests {
+    use proptest::strategy::ValueTree;
+
+    use super::*;
+    use crate::arb_sized;
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn successive_test_cases_take_successive_bytes() {
+        let strategy = IteratorArbStrategy::new(arb_sized::<u8>(1), 1..=3);
+        let mut runner = TestRunner::deterministic();
+        let values = (0..3)
+            .map(|_| strategy.new_tree(&mut runner).unwrap().current())
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![1, 2, 3], values);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn exhausted_iterator_falls_back_to_random_data() {
+        let strategy = IteratorArbStrategy::new(arb_sized::<[u8; 64]>(64), [7, 7].into_iter());
+        let tree = strategy.new_tree(&mut TestRunner::deterministic()).unwrap();
+
+        let bytes = tree.raw_bytes();
+        assert_eq!([7, 7], bytes[..2]);
+        assert!(bytes[2..].iter().any(|byte| *byte != 0));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn clones_share_the_iterator() {
+        let strategy = IteratorArbStrategy::new(arb_sized::<u8>(1), 1..=2);
+        let mut runner = TestRunner::deterministic();
+        strategy.clone().new_tree(&mut runner).unwrap();
+
+        assert_eq!(2, strategy.new_tree(&mut runner).unwrap().current());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn values_shrink_as_usual() {
+        let mut tree = arb_from_iterator::<u8, _>([200])
+            .new_tree(&mut TestRunner::deterministic())
+            .unwrap();
+        while tree.simplify() {}
+
+        assert_eq!(0, tree.current());
+    }
+}
@@ -0,0 +1,80 @@
+//! Sharing test bodies between [cargo-fuzz](https://github.com/rust-fuzz/cargo-fuzz)
+//! and proptest, see [`arbitrary_fuzz_target!`](crate::arbitrary_fuzz_target).
+
+#[doc(hidden)]
+pub use libfuzzer_sys as __libfuzzer_sys;
+#[doc(hidden)]
+pub use proptest as __proptest;
+
+/// Defines a cargo-fuzz target and a property test with the same body, so
+/// that the [`arbitrary::Arbitrary`] implementation of the input type serves
+/// both fuzzing and [`arb`](crate::arb).
+///
+/// When built by `cargo fuzz`, which sets `cfg(fuzzing)`, the macro expands to
+/// [`libfuzzer_sys::fuzz_target!`]. In tests, it expands to a test named
+/// `fuzz_target` that runs the body on values of [`arb`](crate::arb) with
+/// proptest's default configuration, shrinking any failing input. Put the
+/// macro at the top level of the fuzz target, next to `#![no_main]`, and run
+/// the property test with `cargo test` in the fuzzing crate. Attributes
+/// before the closure, like `#[ignore]`, apply to the property test only.
+///
+/// This crate depends on `libfuzzer-sys` without its default feature
+/// `link_libfuzzer`, which would link libFuzzer and its `main` into every test
+/// binary. The fuzzing crate must depend on `libfuzzer-sys` with default
+/// features itself, like the `Cargo.toml` that `cargo fuzz init` generates
+/// does, or `cargo fuzz build` finds no libFuzzer runtime to link.
+///
+/// The example is not run: outside of `cfg(test)` and `cfg(fuzzing)`, the
+/// macro expands to nothing.
+///
+/// ```no_run
+/// use proptest_arbitrary_adapter::arbitrary_fuzz_target;
+///
+/// arbitrary_fuzz_target!(|input: (u8, u8)| {
+///     let (a, b) = input;
+///     assert_eq!(a.wrapping_add(b), b.wrapping_add(a));
+/// });
+/// ```
+#[macro_export]
+macro_rules! arbitrary_fuzz_target {
+    ($(#[$attr:meta])* |$input:ident: $ty:ty| $body:block) => {
+        #[cfg(fuzzing)]
+        $crate::fuzz::__libfuzzer_sys::fuzz_target!(|$input: $ty| $body);
+
+        #[cfg(all(test, not(fuzzing)))]
+        #[test]
+        $(#[$attr])*
+        fn fuzz_target() {
+            let mut runner = $crate::fuzz::__proptest::test_runner::TestRunner::default();
+            let result = runner.run(&$crate::arb::<$ty>(), |$input: $ty| {
+                $body;
+                Ok(())
+            });
+            if let Err(error) = result {
+                panic!("{error}");
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, feature(coverage_attribute))]
+// Not on `wasm32`, which cargo-fuzz does not support.
+mod tests {
+    // Both invocations expand to a test named `fuzz_target`, which runs the
+    // body as a property test.
+
+    crate::arbitrary_fuzz_target!(|input: (u8, u8)| {
+        let (a, b) = input;
+        assert_eq!(a.wrapping_add(b), b.wrapping_add(a));
+    });
+
+    mod failing {
+        crate::arbitrary_fuzz_target!(
+            #[should_panic(expected = "input < 10")]
+            |input: u8| {
+                assert!(input < 10);
+            }
+        );
+    }
+}
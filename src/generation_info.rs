@@ -0,0 +1,67 @@
+use crate::ArbInterop;
+use crate::ArbValueTree;
+
+/// A snapshot of how an [`ArbValueTree`] generated and shrank its value, for
+/// debugging flaky tests and slow shrinking. See
+/// [`ArbValueTree::generation_info`] and `RecordingArbStrategy`, which records
+/// it for every test case.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Default, Debug)]
+pub struct GenerationInfo {
+    /// The number of bytes of random data, see [`ArbValueTree::raw_bytes`].
+    pub buffer_size: usize,
+
+    /// The number of bytes the current value was generated from, see
+    /// [`ArbValueTree::bytes_consumed`].
+    pub bytes_consumed: usize,
+
+    /// How often the tree was asked to
+    /// [simplify](proptest::strategy::ValueTree::simplify).
+    pub simplify_steps: u32,
+
+    /// How often the tree was asked to
+    /// [complicate](proptest::strategy::ValueTree::complicate).
+    pub complicate_steps: u32,
+}
+
+impl<A: ArbInterop> ArbValueTree<A> {
+    /// A snapshot of how the tree generated and shrank its value so far.
+    ///
+    /// Generates the current value anew to count the bytes consumed.
+    pub fn generation_info(&self) -> GenerationInfo {
+        GenerationInfo {
+            buffer_size: self.bytes.len(),
+            bytes_consumed: self.bytes_consumed(),
+            simplify_steps: self.simplify_steps,
+            complicate_steps: self.complicate_steps,
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, feature(coverage_attribute))]
+mod tests {
+    use crate::arb_sized;
+    use proptest::strategy::Strategy;
+    use proptest::strategy::ValueTree;
+    use proptest::test_runner::TestRunner;
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn info_reflects_generation() {
+        let mut tree = arb_sized::<u16>(8)
+            .new_tree(&mut TestRunner::deterministic())
+            .unwrap();
+        let info = tree.generation_info();
+        assert_eq!(8, info.buffer_size);
+        assert_eq!(2, info.bytes_consumed);
+        assert_eq!(0, info.simplify_steps);
+        assert_eq!(0, info.complicate_steps);
+
+        tree.simplify();
+        tree.complicate();
+        tree.complicate();
+        let info = tree.generation_info();
+        assert_eq!(1, info.simplify_steps);
+        assert_eq!(2, info.complicate_steps);
+    }
+}
@@ -0,0 +1,169 @@
+use proptest::prelude::RngCore;
+use proptest::test_runner::TestRunner;
+
+use crate::ArbInterop;
+use crate::ArbStrategy;
+use crate::ArbValueTree;
+use crate::arb;
+
+/// The initial size used by [`arb_growing`].
+const DEFAULT_INITIAL_SIZE: usize = 8;
+
+/// The maximum size used by [`arb_growing`].
+const DEFAULT_MAX_SIZE: usize = 4096;
+
+/// A [`proptest::strategy::Strategy`] that starts out with a small buffer of
+/// random data and doubles its size every time the [`arbitrary::Arbitrary`]
+/// implementation rejects it, up to some maximum size.
+///
+/// Useful for types that need an unknown amount of random data to be
+/// constructed. Like [`arb`], gives up after the default number of
+/// [`max_retries`](crate::ArbConfig::max_retries) rejections. See
+/// [`arb_growing`] and [`arb_growing_sized`].
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct GrowingArbStrategy<A: ArbInterop> {
+    initial_size: usize,
+    max_size: usize,
+
+    /// Names the strategy in failure messages, limits the number of
+    /// rejections and configures shrinking. Its sizes are unused.
+    strategy: ArbStrategy<A>,
+}
+
+impl<A: ArbInterop> GrowingArbStrategy<A> {
+    /// # Panics
+    ///
+    /// Panics if `initial_size` is greater than `max_size`.
+    pub fn new(initial_size: usize, max_size: usize) -> Self {
+        assert!(
+            initial_size <= max_size,
+            "initial size {initial_size} exceeds maximum size {max_size}"
+        );
+
+        Self {
+            initial_size,
+            max_size,
+            strategy: arb(),
+        }
+    }
+
+    /// The size of random data after the given number of rejections: the
+    /// initial size, doubled for every rejection, up to the maximum size.
+    fn size_after(&self, num_retries: u32) -> usize {
+        let size = match (self.initial_size, num_retries) {
+            (0, 0) => 0,
+            (0, num_retries) => 2_usize.saturating_pow(num_retries - 1),
+            (size, num_retries) => size.saturating_mul(2_usize.saturating_pow(num_retries)),
+        };
+
+        size.min(self.max_size)
+    }
+}
+
+impl<A: ArbInterop> proptest::strategy::Strategy for GrowingArbStrategy<A> {
+    type Tree = ArbValueTree<A>;
+    type Value = A;
+
+    fn new_tree(&self, run: &mut TestRunner) -> proptest::strategy::NewTree<Self> {
+        self.strategy.new_tree_sized(
+            run,
+            |_, num_retries| Ok(self.size_after(num_retries)),
+            |run, bytes| run.rng().fill_bytes(bytes),
+            || (),
+            ArbValueTree::new,
+        )
+    }
+}
+
+/// Constructs a [`GrowingArbStrategy`] for a given [`arbitrary::Arbitrary`]
+/// type, starting with 8 bytes of random data and growing up to 4096 bytes.
+pub fn arb_growing<A: ArbInterop>() -> GrowingArbStrategy<A> {
+    arb_growing_sized(DEFAULT_INITIAL_SIZE, DEFAULT_MAX_SIZE)
+}
+
+/// Constructs a [`GrowingArbStrategy`] for a given [`arbitrary::Arbitrary`]
+/// type, starting with `initial` bytes of random data and growing up to `max`
+/// bytes.
+///
+/// # Panics
+///
+/// Panics if `initial` is greater than `max`.
+pub fn arb_growing_sized<A: ArbInterop>(initial: usize, max: usize) -> GrowingArbStrategy<A> {
+    GrowingArbStrategy::new(initial, max)
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, feature(coverage_attribute))]
+mod tests {
+    use arbitrary::Arbitrary;
+    use proptest::prelude::*;
+    use test_strategy::proptest;
+
+    use super::*;
+
+    /// Requires at least 1000 bytes to be generated from.
+    #[derive(Debug, Clone)]
+    struct Large(usize);
+
+    impl<'a> Arbitrary<'a> for Large {
+        fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+            if u.len() < 1000 {
+                return Err(arbitrary::Error::NotEnoughData);
+            }
+
+            Ok(Self(u.len()))
+        }
+    }
+
+    #[proptest]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn types_requiring_large_inputs_can_be_generated(#[strategy(arb_growing())] large: Large) {
+        prop_assert_eq!(1024, large.0);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn growth_stops_at_max_size() {
+        let strategy = arb_growing_sized::<Large>(8, 512);
+        let mut runner = TestRunner::new(ProptestConfig {
+            max_local_rejects: 100,
+            ..ProptestConfig::default()
+        });
+
+        assert!(strategy.new_tree(&mut runner).is_err());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn sizes_double_with_every_rejection() {
+        let sizes = |strategy: GrowingArbStrategy<Large>| {
+            (0..5).map(|n| strategy.size_after(n)).collect::<Vec<_>>()
+        };
+
+        assert_eq!(vec![3, 6, 12, 20, 20], sizes(arb_growing_sized(3, 20)));
+        assert_eq!(vec![0, 1, 2, 4, 8], sizes(arb_growing_sized(0, 20)));
+        assert_eq!(
+            usize::MAX,
+            arb_growing_sized::<Large>(1, usize::MAX).size_after(u32::MAX)
+        );
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn too_many_rejections_fail() {
+        let strategy = arb_growing_sized::<Large>(8, 512);
+        let reason = strategy
+            .new_tree(&mut TestRunner::deterministic())
+            .unwrap_err();
+
+        let expected = format!("{}: too many rejections", core::any::type_name::<Large>());
+        assert!(reason.message().starts_with(&expected));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[should_panic]
+    fn initial_size_must_not_exceed_max_size() {
+        let _ = arb_growing_sized::<Large>(16, 8);
+    }
+}
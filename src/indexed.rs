@@ -0,0 +1,263 @@
+use alloc::format;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::fmt::Debug;
+use core::marker::PhantomData;
+use core::sync::atomic::AtomicU64;
+use core::sync::atomic::Ordering;
+
+use proptest::num::u64::BinarySearch;
+use proptest::strategy::Strategy;
+use proptest::strategy::ValueTree;
+use proptest::test_runner::TestRunner;
+
+use crate::ArbInterop;
+use crate::arb;
+
+/// A [`proptest::strategy::Strategy`] that generates the values at successive
+/// indices of a deterministic enumeration, one per test case, instead of from
+/// random data. See [`arb_by_index`].
+///
+/// The value at index `i` is generated from `size` bytes holding `i` in
+/// little-endian order, so `0` maps to all-zero bytes and `1` to a leading
+/// `0x01`. Indices beyond `256^size` wrap around, and bytes beyond the eighth
+/// are always zero. Useful for combinatorial coverage of small types.
+///
+/// Since the enumeration should be complete, the strategy fails on any
+/// rejected bytes. Clones of the strategy share the enumeration.
+#[derive(Clone, Debug)]
+pub struct IndexedArbStrategy<A: ArbInterop> {
+    size: usize,
+
+    /// The index of the next value to generate.
+    next_index: Arc<AtomicU64>,
+    _ph: PhantomData<fn() -> A>,
+}
+
+impl<A: ArbInterop> IndexedArbStrategy<A> {
+    /// Enumerates values generated from `size` bytes, starting at index
+    /// `start`.
+    pub fn new(size: usize, start: u64) -> Self {
+        Self {
+            size,
+            next_index: Arc::new(AtomicU64::new(start)),
+            _ph: PhantomData,
+        }
+    }
+}
+
+/// The bytes the value at the given `index` is generated from.
+fn bytes_at(size: usize, index: u64) -> Vec<u8> {
+    let mut bytes = index.to_le_bytes().to_vec();
+    bytes.resize(size, 0);
+
+    bytes
+}
+
+fn value_at<A: ArbInterop>(size: usize, index: u64) -> Result<A, arbitrary::Error> {
+    A::arbitrary(&mut arbitrary::Unstructured::new(&bytes_at(size, index)))
+}
+
+impl<A: ArbInterop> Strategy for IndexedArbStrategy<A> {
+    type Tree = IndexedValueTree<A>;
+    type Value = A;
+
+    fn new_tree(&self, _: &mut TestRunner) -> proptest::strategy::NewTree<Self> {
+        let index = self.next_index.fetch_add(1, Ordering::Relaxed);
+        let curr = value_at(self.size, index).map_err(|e| format!("{e} for index {index}"))?;
+
+        Ok(IndexedValueTree {
+            size: self.size,
+            search: BinarySearch::new(index),
+            curr,
+        })
+    }
+}
+
+/// The [`proptest::strategy::ValueTree`] of an [`IndexedArbStrategy`].
+///
+/// Shrinks towards lower indices, using binary search. Indices whose bytes the
+/// [`arbitrary::Arbitrary`] implementation rejects are skipped.
+#[derive(Clone, Debug)]
+pub struct IndexedValueTree<A: Debug> {
+    size: usize,
+    search: BinarySearch,
+    curr: A,
+}
+
+impl<A: ArbInterop> IndexedValueTree<A> {
+    /// The index of the current value.
+    pub fn index(&self) -> u64 {
+        self.search.current()
+    }
+}
+
+impl<A: ArbInterop> ValueTree for IndexedValueTree<A> {
+    type Value = A;
+
+    fn current(&self) -> Self::Value {
+        self.curr.clone()
+    }
+
+    fn simplify(&mut self) -> bool {
+        let index = self.index();
+
+        self.search.simplify() && self.settle() && self.index() != index
+    }
+
+    fn complicate(&mut self) -> bool {
+        self.search.complicate() && self.settle()
+    }
+}
+
+impl<A: ArbInterop> IndexedValueTree<A> {
+    /// Generates the value at the index the search proposes, moving on to
+    /// higher indices while the [`arbitrary::Arbitrary`] implementation
+    /// rejects their bytes, which can never reproduce the failure. If need be,
+    /// ends at the lowest index known to reproduce the failure.
+    fn settle(&mut self) -> bool {
+        loop {
+            match value_at(self.size, self.index()) {
+                Ok(curr) => {
+                    self.curr = curr;
+                    return true;
+                }
+                Err(_) => {
+                    if !self.search.complicate() {
+                        return false;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Constructs an [`IndexedArbStrategy`] for a given [`arbitrary::Arbitrary`]
+/// type, sized like [`arb`] does, starting at index `start`.
+///
+/// ```
+/// # use proptest::strategy::Strategy;
+/// # use proptest::strategy::ValueTree;
+/// # use proptest::test_runner::TestRunner;
+/// # use proptest_arbitrary_adapter::arb_by_index;
+/// let strategy = arb_by_index::<u8>(0);
+/// let mut runner = TestRunner::deterministic();
+/// let values = (0..256)
+///     .map(|_| strategy.new_tree(&mut runner).unwrap().current())
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!((0..=u8::MAX).collect::<Vec<_>>(), values);
+/// ```
+pub fn arb_by_index<A: ArbInterop>(start: u64) -> IndexedArbStrategy<A> {
+    IndexedArbStrategy::new(arb::<A>().buffer_size(), start)
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, feature(coverage_attribute))]
+mod tests {
+    use alloc::vec;
+
+    use arbitrary::Arbitrary;
+
+    use super::*;
+    use crate::test_util::Bytes;
+
+    /// Rejects odd first bytes.
+    #[derive(Debug, Clone)]
+    struct Even(u8);
+
+    impl<'a> Arbitrary<'a> for Even {
+        fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+            let byte = u8::arbitrary(u)?;
+            if byte % 2 == 1 {
+                return Err(arbitrary::Error::IncorrectFormat);
+            }
+
+            Ok(Self(byte))
+        }
+    }
+
+    fn generate<A: ArbInterop>(strategy: &IndexedArbStrategy<A>, n: usize) -> Vec<A> {
+        let mut runner = TestRunner::deterministic();
+        (0..n)
+            .map(|_| strategy.new_tree(&mut runner).unwrap().current())
+            .collect()
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn indices_are_little_endian() {
+        let strategy = IndexedArbStrategy::<Bytes>::new(3, 255);
+        let expected = [vec![255, 0, 0], vec![0, 1, 0], vec![1, 1, 0]];
+
+        assert_eq!(expected.map(Bytes).to_vec(), generate(&strategy, 3));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn indices_wrap_around() {
+        let strategy = IndexedArbStrategy::<u8>::new(1, 255);
+
+        assert_eq!(vec![255, 0, 1], generate(&strategy, 3));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn clones_share_the_enumeration() {
+        let strategy = arb_by_index::<u8>(0);
+        generate(&strategy.clone(), 10);
+
+        assert_eq!(vec![10, 11], generate(&strategy, 2));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn values_shrink_towards_lower_indices() {
+        let strategy = arb_by_index::<u16>(1000);
+        let mut tree = strategy.new_tree(&mut TestRunner::deterministic()).unwrap();
+
+        // Values of at least 100 reproduce the failure.
+        let mut is_simplified = tree.simplify();
+        while is_simplified {
+            is_simplified = if tree.current() < 100 {
+                tree.complicate()
+            } else {
+                tree.simplify()
+            };
+        }
+
+        assert_eq!(100, tree.current());
+        assert_eq!(100, tree.index());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn rejected_indices_are_skipped_while_shrinking() {
+        let strategy = IndexedArbStrategy::<Even>::new(1, 100);
+        let mut tree = strategy.new_tree(&mut TestRunner::deterministic()).unwrap();
+        let mut is_simplified = tree.simplify();
+        while is_simplified {
+            assert_eq!(0, tree.current().0 % 2);
+
+            // Values of at least 31 reproduce the failure.
+            is_simplified = if tree.current().0 < 31 {
+                tree.complicate()
+            } else {
+                tree.simplify()
+            };
+        }
+
+        assert_eq!(32, tree.current().0);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn rejected_indices_fail() {
+        let strategy = IndexedArbStrategy::<Even>::new(1, 1);
+        let reason = strategy
+            .new_tree(&mut TestRunner::deterministic())
+            .unwrap_err();
+
+        assert!(reason.message().contains("for index 1"));
+    }
+}
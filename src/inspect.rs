@@ -0,0 +1,146 @@
+use core::fmt::Debug;
+use core::fmt::Formatter;
+
+use proptest::strategy::Strategy;
+use proptest::test_runner::TestRunner;
+
+use crate::ArbInterop;
+use crate::ArbStrategy;
+use crate::ArbValueTree;
+
+/// A [`proptest::strategy::Strategy`] like [`ArbStrategy`] that passes each
+/// generated value to a function before handing it to the test, like
+/// [`Iterator::inspect`]. See [`ArbStrategy::inspect`].
+///
+/// Shrunk values are not passed to the function.
+#[derive(Clone)]
+pub struct InspectStrategy<A: ArbInterop, F> {
+    strategy: ArbStrategy<A>,
+    f: F,
+}
+
+/// A [`proptest::strategy::Strategy`] like [`ArbStrategy`] that passes the
+/// random data of each generated value to a function before handing the value
+/// to the test. See [`ArbStrategy::inspect_bytes`].
+#[derive(Clone)]
+pub struct InspectBytesStrategy<A: ArbInterop, F> {
+    strategy: ArbStrategy<A>,
+    f: F,
+}
+
+impl<A: ArbInterop> ArbStrategy<A> {
+    /// Turns this strategy into an [`InspectStrategy`], which calls `f` on each
+    /// generated value, for example to log it while debugging a test.
+    pub fn inspect<F: Fn(&A)>(self, f: F) -> InspectStrategy<A, F> {
+        InspectStrategy { strategy: self, f }
+    }
+
+    /// Turns this strategy into an [`InspectBytesStrategy`], which calls `f` on
+    /// the random data of each generated value.
+    pub fn inspect_bytes<F: Fn(&[u8])>(self, f: F) -> InspectBytesStrategy<A, F> {
+        InspectBytesStrategy { strategy: self, f }
+    }
+}
+
+impl<A: ArbInterop, F> Debug for InspectStrategy<A, F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("InspectStrategy")
+            .field("strategy", &self.strategy)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<A: ArbInterop, F> Debug for InspectBytesStrategy<A, F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("InspectBytesStrategy")
+            .field("strategy", &self.strategy)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<A: ArbInterop, F: Fn(&A)> Strategy for InspectStrategy<A, F> {
+    type Tree = ArbValueTree<A>;
+    type Value = A;
+
+    fn new_tree(&self, run: &mut TestRunner) -> proptest::strategy::NewTree<Self> {
+        let tree = self.strategy.new_tree(run)?;
+        (self.f)(&tree.curr);
+
+        Ok(tree)
+    }
+}
+
+impl<A: ArbInterop, F: Fn(&[u8])> Strategy for InspectBytesStrategy<A, F> {
+    type Tree = ArbValueTree<A>;
+    type Value = A;
+
+    fn new_tree(&self, run: &mut TestRunner) -> proptest::strategy::NewTree<Self> {
+        let tree = self.strategy.new_tree(run)?;
+        (self.f)(tree.raw_bytes());
+
+        Ok(tree)
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, feature(coverage_attribute))]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use core::cell::RefCell;
+
+    use proptest::strategy::ValueTree;
+
+    use super::*;
+    use crate::arb;
+    use crate::arb_sized;
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn each_generated_value_is_inspected() {
+        let seen = RefCell::new(Vec::new());
+        let strategy = arb::<u64>().inspect(|value| seen.borrow_mut().push(*value));
+        let mut runner = TestRunner::deterministic();
+        let values = (0..10)
+            .map(|_| strategy.new_tree(&mut runner).unwrap().current())
+            .collect::<Vec<_>>();
+
+        assert_eq!(values, seen.into_inner());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn inspection_does_not_change_values() {
+        let mut runner = TestRunner::deterministic();
+        let mut other_runner = TestRunner::deterministic();
+        for _ in 0..10 {
+            let value = arb::<u64>().inspect(|_| ()).new_tree(&mut runner).unwrap();
+            let expected = arb::<u64>().new_tree(&mut other_runner).unwrap();
+            assert_eq!(expected, value);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn random_data_is_inspected() {
+        let seen = RefCell::new(Vec::new());
+        let strategy =
+            arb_sized::<u8>(16).inspect_bytes(|bytes| seen.borrow_mut().push(bytes.to_vec()));
+        let tree = strategy.new_tree(&mut TestRunner::deterministic()).unwrap();
+
+        assert_eq!(vec![tree.raw_bytes().to_vec()], *seen.borrow());
+        assert_eq!(16, seen.borrow()[0].len());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn shrinking_is_unaffected() {
+        let seen = RefCell::new(0);
+        let strategy = arb_sized::<u64>(8).inspect(|_| *seen.borrow_mut() += 1);
+        let mut tree = strategy.new_tree(&mut TestRunner::deterministic()).unwrap();
+        while tree.simplify() {}
+
+        assert_eq!(0, tree.current());
+        assert_eq!(1, seen.into_inner());
+    }
+}
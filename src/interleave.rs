@@ -0,0 +1,96 @@
+use alloc::sync::Arc;
+use core::sync::atomic::AtomicBool;
+use core::sync::atomic::Ordering;
+
+use proptest::strategy::Strategy;
+use proptest::test_runner::TestRunner;
+
+use crate::ArbInterop;
+use crate::ArbStrategy;
+use crate::ArbValueTree;
+use crate::arb_sized;
+
+/// A [`proptest::strategy::Strategy`] that alternates between two
+/// [`ArbStrategy`]s from one test case to the next, starting with the small
+/// one.
+///
+/// Useful to cover two size regimes equally, for example tiny inputs for edge
+/// cases and large ones for performance. Values shrink like those of the
+/// strategy that generated them. See [`arb_interleave`].
+///
+/// Clones of the strategy share the alternation.
+#[derive(Clone, Debug)]
+pub struct InterleaveArbStrategy<A: ArbInterop> {
+    small: ArbStrategy<A>,
+    large: ArbStrategy<A>,
+
+    /// Whether the next test case uses the large strategy.
+    is_large_next: Arc<AtomicBool>,
+}
+
+impl<A: ArbInterop> InterleaveArbStrategy<A> {
+    /// Alternates between the `small` and the `large` strategy.
+    pub fn new(small: ArbStrategy<A>, large: ArbStrategy<A>) -> Self {
+        Self {
+            small,
+            large,
+            is_large_next: Arc::default(),
+        }
+    }
+}
+
+impl<A: ArbInterop> Strategy for InterleaveArbStrategy<A> {
+    type Tree = ArbValueTree<A>;
+    type Value = A;
+
+    fn new_tree(&self, run: &mut TestRunner) -> proptest::strategy::NewTree<Self> {
+        if self.is_large_next.fetch_xor(true, Ordering::Relaxed) {
+            self.large.new_tree(run)
+        } else {
+            self.small.new_tree(run)
+        }
+    }
+}
+
+/// Constructs an [`InterleaveArbStrategy`] for a given [`arbitrary::Arbitrary`]
+/// type, alternately feeding it `small_size` and `large_size` bytes of random
+/// data.
+pub fn arb_interleave<A: ArbInterop>(
+    small_size: usize,
+    large_size: usize,
+) -> InterleaveArbStrategy<A> {
+    InterleaveArbStrategy::new(arb_sized(small_size), arb_sized(large_size))
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, feature(coverage_attribute))]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    fn sizes(strategy: &InterleaveArbStrategy<u8>, run: &mut TestRunner) -> Vec<usize> {
+        (0..6)
+            .map(|_| strategy.new_tree(run).unwrap().raw_bytes().len())
+            .collect()
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn sizes_alternate() {
+        let strategy = arb_interleave::<u8>(1, 64);
+        let sizes = sizes(&strategy, &mut TestRunner::deterministic());
+
+        assert_eq!([1, 64, 1, 64, 1, 64], *sizes);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn clones_share_the_alternation() {
+        let strategy = arb_interleave::<u8>(1, 64);
+        let mut runner = TestRunner::deterministic();
+        strategy.clone().new_tree(&mut runner).unwrap();
+
+        assert_eq!([64, 1, 64, 1, 64, 1], *sizes(&strategy, &mut runner));
+    }
+}
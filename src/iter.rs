@@ -0,0 +1,127 @@
+use alloc::vec::Vec;
+
+use proptest::strategy::Strategy;
+use proptest::strategy::ValueTree;
+use proptest::test_runner::TestRunner;
+
+use crate::ArbInterop;
+use crate::ArbStrategy;
+
+/// An endless [`Iterator`] of values generated by an [`ArbStrategy`], for use
+/// outside of property tests.
+///
+/// Since it is a source of random values, the iterator never ends. Use, for
+/// example, [`Iterator::take`] to limit the number of values.
+///
+/// # Panics
+///
+/// Iteration panics if the strategy fails to generate a value, for example
+/// because the [`arbitrary::Arbitrary`] implementation rejected too much random
+/// data.
+#[derive(Debug)]
+pub struct ArbIter<A: ArbInterop> {
+    strategy: ArbStrategy<A>,
+    runner: TestRunner,
+}
+
+impl<A: ArbInterop> Iterator for ArbIter<A> {
+    type Item = A;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.strategy.new_tree(&mut self.runner) {
+            Ok(tree) => Some(tree.current()),
+            Err(reason) => panic!("failed to generate value: {reason}"),
+        }
+    }
+}
+
+impl<A: ArbInterop> ArbStrategy<A> {
+    /// Turns this strategy into an [`ArbIter`] that uses the given `runner` as
+    /// its source of randomness.
+    pub fn into_iter_with_runner(self, runner: TestRunner) -> ArbIter<A> {
+        ArbIter {
+            strategy: self,
+            runner,
+        }
+    }
+
+    /// Generates `n` independent values using a [default](TestRunner::default)
+    /// runner, for example for smoke tests outside of property tests.
+    ///
+    /// # Panics
+    ///
+    /// Panics if generating a value fails, for example because the
+    /// [`arbitrary::Arbitrary`] implementation rejects too much random data.
+    pub fn sample_n(&self, n: usize) -> Vec<A> {
+        self.clone().into_iter().take(n).collect()
+    }
+}
+
+impl<A: ArbInterop> IntoIterator for ArbStrategy<A> {
+    type Item = A;
+    type IntoIter = ArbIter<A>;
+
+    /// Turns this strategy into an [`ArbIter`] that uses a
+    /// [default](TestRunner::default) runner as its source of randomness.
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_iter_with_runner(TestRunner::default())
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, feature(coverage_attribute))]
+mod tests {
+    use super::*;
+    use crate::arb;
+
+    #[derive(Debug, Clone, PartialEq, Eq, arbitrary::Arbitrary)]
+    struct Test(u64);
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn values_can_be_sampled() {
+        let values = arb::<Test>().into_iter().take(100).collect::<Vec<_>>();
+
+        assert_eq!(100, values.len());
+        assert!(values.iter().any(|value| *value != values[0]));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn same_runner_gives_same_values() {
+        let sample = || {
+            arb::<Test>()
+                .into_iter_with_runner(TestRunner::deterministic())
+                .take(10)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(sample(), sample());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn n_values_are_sampled() {
+        let values = arb::<Test>().sample_n(100);
+
+        assert_eq!(100, values.len());
+        assert!(values.iter().any(|value| *value != values[0]));
+        assert!(arb::<Test>().sample_n(0).is_empty());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[should_panic(expected = "failed to generate value")]
+    fn failed_sampling_panics() {
+        #[derive(Debug, Clone)]
+        struct Never;
+
+        impl<'a> arbitrary::Arbitrary<'a> for Never {
+            fn arbitrary(_: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+                Err(arbitrary::Error::IncorrectFormat)
+            }
+        }
+
+        arb::<Never>().sample_n(1);
+    }
+}
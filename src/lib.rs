@@ -18,6 +18,26 @@
 //! }
 //! ```
 //!
+//! # Shrinking
+//!
+//! Values shrink by shrinking the random data they are generated from. By
+//! default, the built-in shrinking zeroes bytes and then searches for the
+//! shortest prefix of the random data that still reproduces the failure. It
+//! takes fewer steps than truncating one byte at a time and usually finds
+//! simpler values, so it stays the default for all strategies.
+//!
+//! To shrink differently, pass a [`ShrinkStrategy`] to
+//! [`ArbStrategy::then_shrink_with`], for example
+//! [`LinearTruncateShrinkStrategy`] or [`ZeroBytesShrinkStrategy`], or
+//! implement your own. [`arb_binary_search`] is a shorthand for
+//! [`BinarySearchShrinkStrategy`].
+//!
+//! ```rust
+//! # use proptest_arbitrary_adapter::LinearTruncateShrinkStrategy;
+//! # use proptest_arbitrary_adapter::arb;
+//! let strategy = arb::<Vec<u8>>().then_shrink_with(LinearTruncateShrinkStrategy);
+//! ```
+//!
 //! # Origin
 //!
 //! This code is a copy of the unmaintained crate
@@ -29,7 +49,7 @@
 //! # Caveats
 //!
 //! It only works with types that implement [`arbitrary::Arbitrary`] in a
-//! particular fashion: those conforming to the requirements of [`ArbInterop`].
+//! particular fashion: those conforming to the requirements of [`ArbInterop`](trait@ArbInterop).
 //! These are roughly "types that, when randomly-generated, don't retain
 //! pointers into the random-data buffer wrapped by the
 //! [`arbitrary::Unstructured`] they are generated from". Many implementations
@@ -38,36 +58,430 @@
 //! requirement appears to be a necessary part of the semantic model of
 //! [`proptest`] – generated values have to own their pointer graph, no
 //! borrows. Patches welcome if you can figure out a way to not require it.
+//!
+//! # Features
+//!
+//! - `std` (default): Disable it for `no_std` targets, which need to provide
+//!   [`alloc`] instead. Anything that accesses the file system requires `std`.
+//! - `debug-shrink`: Exposes `ArbValueTree::shrink_path`.
+//! - `serde`: Allows saving and loading [`ArbValueTree`]s. Implies `std`.
+//! - `stats`: Exposes `ArbStrategy::with_stats` to count rejected random data.
+//! - `derive`: Provides `#[derive(ArbInterop)]`, which turns unmet requirements
+//!   of [`ArbInterop`](trait@ArbInterop) into actionable compile errors.
+//! - `rayon`: After the first rejection of random data, searches for acceptable
+//!   random data in parallel. Implies `std`.
+//! - `quickcheck`: Adapts `quickcheck::Arbitrary` implementations, too, using
+//!   `QcArbStrategy`. Implies `std`.
+//! - `smallvec`: Stores up to 64 bytes of random data of [`ArbValueTree`]s
+//!   inline instead of on the heap. Trees still allocate for shrinking, and the
+//!   benchmark `smallvec` shows no speedup, so measure before enabling it.
+//! - `verbose-bytes`: Includes the random data in hexadecimal in the [`Debug`]
+//!   output of [`ArbValueTree`]s, see `ArbValueTree::bytes_as_hex`.
+//! - `toml`: Allows saving and loading [`ArbStrategy`] configurations as TOML,
+//!   using `ArbStrategy::to_toml` and `ArbStrategy::from_toml`. Implies `serde`.
+//! - `tracing`: Emits [`tracing`](https://crates.io/crates/tracing) spans and
+//!   events when generating, simplifying, and complicating values, naming the
+//!   type, the size of the random data, and whether the step succeeded.
+//! - `fuzz`: Provides `arbitrary_fuzz_target!`, which defines a
+//!   [cargo-fuzz](https://github.com/rust-fuzz/cargo-fuzz) target and a
+//!   property test with the same body. Implies `std`. The fuzzing crate
+//!   must depend on `libfuzzer-sys` with default features to link libFuzzer.
+//! - `criterion`: Provides the module `bench`, which generates reproducible
+//!   benchmark inputs, for example for
+//!   [criterion](https://crates.io/crates/criterion), outside of property
+//!   tests. Does not depend on criterion itself.
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+use alloc::format;
+use alloc::string::String;
+use alloc::sync::Arc;
+use alloc::vec;
+use alloc::vec::Vec;
 use core::fmt::Debug;
-use std::marker::PhantomData;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::hash::Hash;
+use core::hash::Hasher;
+use core::marker::PhantomData;
 
 use proptest::prelude::RngCore;
+use proptest::strategy::Filter;
+use proptest::strategy::FilterMap;
+use proptest::strategy::Flatten;
+use proptest::strategy::Just;
+use proptest::strategy::Map;
+use proptest::strategy::Strategy;
+use proptest::strategy::ValueTree;
+use proptest::test_runner::Config;
+use proptest::test_runner::Reason;
+use proptest::test_runner::RngAlgorithm;
+use proptest::test_runner::TestError;
+use proptest::test_runner::TestRng;
 use proptest::test_runner::TestRunner;
 
+use crate::shrink::SharedShrinker;
+
+/// Checks that a type satisfies all requirements of [`ArbInterop`](trait@ArbInterop), which is
+/// then implemented automatically. Every unmet requirement is reported as a
+/// separate compile error naming it.
+///
+/// ```compile_fail
+/// use proptest_arbitrary_adapter::ArbInterop;
+///
+/// // error: the trait bound `NotClone: Clone` is not satisfied
+/// #[derive(Debug, arbitrary::Arbitrary, ArbInterop)]
+/// struct NotClone(u8);
+/// ```
+#[cfg(feature = "derive")]
+pub use proptest_arbitrary_adapter_derive::ArbInterop;
+
+pub use crate::annotated::AnnotatedArbStrategy;
+pub use crate::batch::BatchArbStrategy;
+pub use crate::batch::BatchValueTree;
+pub use crate::biased::BiasedArbStrategy;
+pub use crate::biased::arb_biased;
+pub use crate::bounded::BoundedArbStrategy;
+pub use crate::bounded::arb_bounded;
+pub use crate::boxed::ArbBoxed;
+#[cfg(feature = "std")]
+pub use crate::cached::CachedArbStrategy;
+#[cfg(feature = "std")]
+pub use crate::cached::arb_cached;
+pub use crate::chain::ChainArbStrategy;
+pub use crate::chain::arb_chain;
+#[cfg(feature = "std")]
+pub use crate::checked::CheckedArbStrategy;
+#[cfg(feature = "std")]
+pub use crate::checked::arb_checked;
+pub use crate::config::ArbConfig;
+pub use crate::config::ArbConfigBuilder;
+pub use crate::config::SizeMode;
+#[cfg(feature = "std")]
+pub use crate::corpus::CorpusArbStrategy;
+#[cfg(feature = "std")]
+pub use crate::coverage::CoverageArbStrategy;
+#[cfg(feature = "std")]
+pub use crate::coverage::arb_coverage;
+pub use crate::dictionary::DictionaryArbStrategy;
+pub use crate::dictionary::arb_with_dictionary;
+pub use crate::entropy::EntropyArbStrategy;
+pub use crate::entropy::arb_with_entropy;
+pub use crate::exhaustive::ExhaustiveArbStrategy;
+#[cfg(feature = "std")]
+pub use crate::from_iterator::IteratorArbStrategy;
+#[cfg(feature = "std")]
+pub use crate::from_iterator::arb_from_iterator;
+pub use crate::generation_info::GenerationInfo;
+pub use crate::growing::GrowingArbStrategy;
+pub use crate::growing::arb_growing;
+pub use crate::growing::arb_growing_sized;
+pub use crate::indexed::IndexedArbStrategy;
+pub use crate::indexed::IndexedValueTree;
+pub use crate::indexed::arb_by_index;
+pub use crate::inspect::InspectBytesStrategy;
+pub use crate::inspect::InspectStrategy;
+pub use crate::interleave::InterleaveArbStrategy;
+pub use crate::interleave::arb_interleave;
+pub use crate::iter::ArbIter;
+pub use crate::map_bytes::MapBytesArbStrategy;
+#[cfg(feature = "std")]
+pub use crate::mutating::MutatingArbStrategy;
+#[cfg(feature = "std")]
+pub use crate::mutating::arb_mutating;
+pub use crate::no_shrink::ArbNoShrink;
+pub use crate::no_shrink::ArbNoShrinkTree;
+pub use crate::no_shrink::arb_no_shrink;
+pub use crate::no_shrink::arb_sized_no_shrink;
+pub use crate::option::ArbOptionStrategy;
+pub use crate::option::ArbOptionValueTree;
+pub use crate::option::arb_option;
+#[cfg(feature = "toml")]
+pub use crate::persist::ConfigError;
+pub use crate::prioritized::PrioritizedArbStrategy;
+pub use crate::prioritized::arb_prioritized;
+#[cfg(feature = "quickcheck")]
+pub use crate::qc::QcArbInterop;
+#[cfg(feature = "quickcheck")]
+pub use crate::qc::QcArbStrategy;
+#[cfg(feature = "quickcheck")]
+pub use crate::qc::QcValueTree;
+#[cfg(feature = "quickcheck")]
+pub use crate::qc::qc_arb;
+#[cfg(feature = "std")]
+pub use crate::recording::RecordingArbStrategy;
+#[cfg(feature = "std")]
+pub use crate::recording::RecordingArbValueTree;
+pub use crate::replay::ReplayArbStrategy;
+pub use crate::replay::arb_replay;
+#[cfg(feature = "std")]
+pub use crate::replay::arb_replay_from_file;
+pub use crate::result::ArbResultStrategy;
+pub use crate::result::ArbResultValueTree;
+pub use crate::result::arb_result;
+#[cfg(feature = "std")]
+pub use crate::seeded::SeededArbStrategy;
+#[cfg(feature = "std")]
+pub use crate::seeded::arb_seed;
+pub use crate::shrink::BinarySearchShrinkStrategy;
+pub use crate::shrink::LinearTruncateShrinkStrategy;
+pub use crate::shrink::ShrinkState;
+pub use crate::shrink::ShrinkStrategy;
+pub use crate::shrink::ShrinkingArbStrategy;
+pub use crate::shrink::ZeroBytesShrinkStrategy;
+pub use crate::shrink::arb_binary_search;
+pub use crate::shuffled::ShuffledArbStrategy;
+#[cfg(feature = "std")]
+pub use crate::size_stats::SizeStats;
+#[cfg(feature = "stats")]
+pub use crate::stats::StatsArbStrategy;
+#[cfg(feature = "std")]
+pub use crate::structured_mutation::StructuredMutationArbStrategy;
+#[cfg(feature = "std")]
+pub use crate::structured_mutation::arb_structured_mutation;
+#[cfg(feature = "std")]
+pub use crate::thread_local::ThreadLocalArbStrategy;
+#[cfg(feature = "std")]
+pub use crate::thread_local::arb_thread_local;
+pub use crate::union::UnionArbStrategy;
+pub use crate::union::UnionValueTree;
+pub use crate::validate::ValidatedArbStrategy;
+pub use crate::validity::Failure;
+pub use crate::validity::FailureSummary;
+pub use crate::weighted::WeightedSizeArbStrategy;
+pub use crate::weighted::arb_weighted_sizes;
+
+#[cfg(feature = "derive")]
+#[doc(hidden)]
+pub mod __private {
+    pub use arbitrary;
+}
+
+mod annotated;
+mod batch;
+#[cfg(feature = "criterion")]
+pub mod bench;
+mod biased;
+mod bounded;
+mod boxed;
+#[cfg(feature = "std")]
+mod cached;
+mod chain;
+#[cfg(feature = "std")]
+mod checked;
+mod config;
+#[cfg(feature = "std")]
+mod corpus;
+#[cfg(feature = "std")]
+mod coverage;
+mod dictionary;
+mod entropy;
+mod exhaustive;
+#[cfg(feature = "std")]
+mod from_iterator;
+#[cfg(feature = "fuzz")]
+pub mod fuzz;
+mod generation_info;
+mod growing;
+mod indexed;
+mod inspect;
+mod interleave;
+mod iter;
+mod map_bytes;
+mod meta;
+#[cfg(feature = "std")]
+mod mutating;
+mod no_shrink;
+mod option;
+#[cfg(feature = "rayon")]
+mod parallel;
+#[cfg(feature = "toml")]
+mod persist;
+pub mod prelude;
+mod prioritized;
+#[cfg(feature = "quickcheck")]
+mod qc;
+#[cfg(feature = "std")]
+mod recording;
+mod replay;
+mod result;
+#[cfg(feature = "std")]
+mod seeded;
+mod shrink;
+mod shuffled;
+#[cfg(feature = "std")]
+mod size_stats;
+#[cfg(feature = "stats")]
+mod stats;
+#[cfg(feature = "std")]
+mod structured_mutation;
+#[cfg(test)]
+mod test_util;
+#[cfg(feature = "std")]
+mod thread_local;
+mod union;
+mod validate;
+mod validity;
+mod weighted;
+mod zip;
+
 /// The subset of possible [`arbitrary::Arbitrary`] implementations that this
 /// crate works with. The main concern here is the `for<'a> Arbitrary<'a>`
 /// business, which (in practice) decouples the generated `Arbitrary` value from
 /// the lifetime of the random buffer it's fed; I can't actually explain how,
 /// because Rust's type system is way over my head.
+///
+/// There is nothing to implement: the trait is implemented automatically for
+/// all types satisfying its bounds, which usually means deriving
+/// [`arbitrary::Arbitrary`], [`Debug`], and [`Clone`]. With the feature
+/// `derive`, `#[derive(ArbInterop)]` reports every unmet bound separately.
 pub trait ArbInterop: for<'a> arbitrary::Arbitrary<'a> + 'static + Debug + Clone {}
 impl<A> ArbInterop for A where A: for<'a> arbitrary::Arbitrary<'a> + 'static + Debug + Clone {}
 
-#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+/// A [`proptest::strategy::Strategy`] for a given [`arbitrary::Arbitrary`]
+/// type. See [`arb`] and [`arb_sized`].
+///
+/// # Thread safety
+///
+/// The strategy holds no values of type `A`, only its configuration and its
+/// shrink strategy, if any. It is [`Send`] and [`Sync`] regardless of `A`, and
+/// can be shared freely across threads.
+///
+/// Strategies compare and hash by their configuration, name, and location,
+/// regardless of the shrink strategy set with [`Self::then_shrink_with`].
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
 pub struct ArbStrategy<A: ArbInterop> {
-    size: usize,
-    _ph: PhantomData<A>,
+    config: ArbConfig,
+
+    /// Identifies the strategy in failure messages.
+    name: &'static str,
+
+    /// The file and line the strategy was created at, if known.
+    location: Option<(&'static str, u32)>,
+
+    /// Replaces the built-in shrinking, if set. See [`Self::then_shrink_with`].
+    shrinker: SharedShrinker,
+    _ph: PhantomData<fn() -> A>,
+}
+
+/// The random data of an [`ArbValueTree`]. With the feature `smallvec`, up to
+/// 64 bytes are stored inline, which covers most simple types.
+#[cfg(not(feature = "smallvec"))]
+type Bytes = Vec<u8>;
+
+/// The random data of an [`ArbValueTree`]. With the feature `smallvec`, up to
+/// 64 bytes are stored inline, which covers most simple types.
+#[cfg(feature = "smallvec")]
+type Bytes = smallvec::SmallVec<[u8; 64]>;
+
+/// Applies `f` to the random data as a [`Vec`], which custom
+/// [`ShrinkStrategy`]s operate on.
+#[cfg(not(feature = "smallvec"))]
+fn with_vec<R>(bytes: &mut Bytes, f: impl FnOnce(&mut Vec<u8>) -> R) -> R {
+    f(bytes)
+}
+
+/// Applies `f` to the random data as a [`Vec`], which custom
+/// [`ShrinkStrategy`]s operate on.
+#[cfg(feature = "smallvec")]
+fn with_vec<R>(bytes: &mut Bytes, f: impl FnOnce(&mut Vec<u8>) -> R) -> R {
+    let mut vec = core::mem::take(bytes).into_vec();
+    let result = f(&mut vec);
+    *bytes = Bytes::from_vec(vec);
+
+    result
 }
 
-#[derive(Debug)]
+/// The [`proptest::strategy::ValueTree`] of an [`ArbStrategy`].
+///
+/// Shrinking happens in two phases. First, the random bytes are zeroed one by
+/// one, starting with the byte of the highest value, which usually simplifies
+/// the value the most. Then, ever shorter prefixes of the bytes are fed to the
+/// [`arbitrary::Arbitrary`] implementation. The shortest prefix that still
+/// reproduces a failure is found using binary search, bounded by the watermarks
+/// `low` and `high`.
+///
+/// Clones shrink independently of each other, which lets external minimizers
+/// explore several shrink paths from the same starting point. Only a custom
+/// [`ShrinkStrategy`] is shared, which holds no state of its own.
+///
+/// # Thread safety
+///
+/// The tree holds values of type `A`. It is [`Send`] or [`Sync`] exactly if `A`
+/// is.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ArbValueTree<A: Debug> {
-    bytes: Vec<u8>,
+    bytes: Bytes,
     curr: A,
     prev: Option<A>,
+
+    /// The length of the prefix of `bytes` that `curr` was generated from.
     next: usize,
+
+    /// The length of the prefix of `bytes` that `prev` was generated from.
+    prev_next: Option<usize>,
+
+    /// All prefixes shorter than this are known to not reproduce the failure.
+    low: usize,
+
+    /// The shortest prefix known to reproduce the failure.
+    high: usize,
+
+    /// How many more times the value may be simplified.
+    steps_left: usize,
+
+    /// How often [`ValueTree::simplify`] was called.
+    #[cfg_attr(feature = "serde", serde(default))]
+    simplify_steps: u32,
+
+    /// How often [`ValueTree::complicate`] was called.
+    #[cfg_attr(feature = "serde", serde(default))]
+    complicate_steps: u32,
+
+    phase: ShrinkPhase,
+
+    /// The indices and original values of the non-zero bytes not yet zeroed,
+    /// ordered such that the byte of the highest value comes last.
+    shrink_candidates: Vec<(usize, u8)>,
+
+    /// The index and original value of the byte zeroed by the most recent
+    /// simplification, if any.
+    zeroed: Option<(usize, u8)>,
+
+    /// The bytes before the most recent [injection](Self::inject_bytes), if
+    /// nothing happened since.
+    injected: Option<Bytes>,
+
+    /// Identifies the tree in debug output, see [`Self::annotate`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    label: Option<String>,
+
+    /// Whether the [`Debug`] output includes [`Self::bytes_as_hex`]. See
+    /// [`ArbStrategy::with_verbose_bytes`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    verbose_bytes: bool,
+
+    /// Replaces the built-in shrinking, if set. See
+    /// [`ArbStrategy::with_shrink_strategy`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    shrinker: Option<(Arc<dyn ShrinkStrategy>, ShrinkState)>,
 }
 
-impl<A: ArbInterop> proptest::strategy::ValueTree for ArbValueTree<A> {
+/// The phases of shrinking an [`ArbValueTree`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum ShrinkPhase {
+    /// Zero the `shrink_candidates`, one at a time.
+    SmartShrink,
+
+    /// Feed shorter prefixes of the bytes to the `Arbitrary` implementation.
+    Truncating,
+}
+
+impl<A: ArbInterop> ValueTree for ArbValueTree<A> {
     type Value = A;
 
     fn current(&self) -> Self::Value {
@@ -75,43 +489,521 @@ impl<A: ArbInterop> proptest::strategy::ValueTree for ArbValueTree<A> {
     }
 
     fn simplify(&mut self) -> bool {
-        if self.next == 0 {
-            return false;
-        }
-        self.next -= 1;
-        let Ok(simpler) = Self::gen_one_with_size(&self.bytes, self.next) else {
-            return false;
-        };
+        self.simplify_steps = self.simplify_steps.saturating_add(1);
+        #[cfg(feature = "tracing")]
+        let _span =
+            tracing::trace_span!("simplify", type_name = core::any::type_name::<A>()).entered();
 
-        // Throw away the previous value and set the current value as prev.
-        // Advance the iterator and set the current value to the next one.
-        self.prev = Some(core::mem::replace(&mut self.curr, simpler));
+        let simplified = self.steps_left > 0 && self.simplify_once();
 
-        true
+        #[cfg(feature = "tracing")]
+        tracing::trace!(buffer_size = self.bytes.len(), next = self.next, simplified);
+        simplified
     }
 
     fn complicate(&mut self) -> bool {
+        self.complicate_steps = self.complicate_steps.saturating_add(1);
+        #[cfg(feature = "tracing")]
+        let _span =
+            tracing::trace_span!("complicate", type_name = core::any::type_name::<A>()).entered();
+
+        let complicated = self.complicate_once();
+
+        #[cfg(feature = "tracing")]
+        tracing::trace!(
+            buffer_size = self.bytes.len(),
+            next = self.next,
+            complicated
+        );
+        complicated
+    }
+}
+
+impl<A: ArbInterop> ArbValueTree<A> {
+    /// Performs one step of [simplification](ValueTree::simplify), given that
+    /// steps are left.
+    fn simplify_once(&mut self) -> bool {
+        // Simplification is only requested if the current value reproduces the
+        // failure. Any previously zeroed byte can stay zero, and any injected
+        // bytes can stay, too.
+        self.zeroed = None;
+        self.injected = None;
+
+        let simplified = if self.shrinker.is_some() {
+            self.simplify_custom()
+        } else {
+            self.simplify_by_zeroing() || self.simplify_by_truncating()
+        };
+        if simplified {
+            self.steps_left -= 1;
+        }
+
+        simplified
+    }
+
+    /// Undoes the previous step of [simplification](ValueTree::simplify), if
+    /// any.
+    fn complicate_once(&mut self) -> bool {
         // We can only complicate if we previously simplified. Complicating
         // twice in a row without interleaved simplification is guaranteed to
         // always yield false for the second call.
-        let Some(prev) = self.prev.take() else {
+        let (Some(prev), Some(prev_next)) = (self.prev.take(), self.prev_next.take()) else {
             return false;
         };
 
-        // Throw away the current value!
+        if let Some(bytes) = self.injected.take() {
+            // The current value does not reproduce the failure. Shrinking
+            // starts over from the bytes before the injection.
+            self.bytes = bytes;
+            self.shrink_candidates = Self::shrink_candidates(&self.bytes[..prev_next]);
+            self.phase = ShrinkPhase::SmartShrink;
+        } else if let Some((shrinker, state)) = &mut self.shrinker {
+            with_vec(&mut self.bytes, |bytes| shrinker.complicate(state, bytes));
+        } else if let Some((index, byte)) = self.zeroed.take() {
+            // The current value does not reproduce the failure. The byte must
+            // not be zeroed.
+            self.bytes[index] = byte;
+        } else {
+            // The current value does not reproduce the failure, and neither
+            // does any shorter prefix. Subsequent simplification searches the
+            // upper half.
+            self.low = self.next + 1;
+        }
+
+        // Throw away the current value! Restore exactly the bytes the previous
+        // value was generated from.
         self.curr = prev;
+        self.next = prev_next;
 
         true
     }
+
+    /// The non-zero bytes, ordered by value and, for equal values, by index.
+    /// Popping them yields the byte of the highest value first, and the one
+    /// furthest towards the end among equals.
+    fn shrink_candidates(bytes: &[u8]) -> Vec<(usize, u8)> {
+        let mut candidates = bytes
+            .iter()
+            .copied()
+            .enumerate()
+            .filter(|&(_, byte)| byte != 0)
+            .collect::<Vec<_>>();
+        candidates.sort_unstable_by_key(|&(index, byte)| (byte, index));
+
+        candidates
+    }
+
+    /// Generates a simpler value from the bytes the custom shrink strategy
+    /// proposes, if any.
+    fn simplify_custom(&mut self) -> bool {
+        let Some((shrinker, state)) = &mut self.shrinker else {
+            return false;
+        };
+
+        while with_vec(&mut self.bytes, |bytes| shrinker.simplify(state, bytes)) {
+            match Self::gen_one_with_size(&self.bytes, self.bytes.len()) {
+                Ok(simpler) => {
+                    self.prev = Some(core::mem::replace(&mut self.curr, simpler));
+                    self.prev_next = Some(self.next);
+                    self.next = self.bytes.len();
+
+                    return true;
+                }
+
+                // Bytes the `Arbitrary` impl rejects can never reproduce the
+                // failure.
+                Err(_) => {
+                    with_vec(&mut self.bytes, |bytes| shrinker.complicate(state, bytes));
+                }
+            }
+        }
+
+        false
+    }
+
+    /// Zeroes the remaining non-zero byte of the highest value within the
+    /// current prefix, if any, and generates a simpler value from the result.
+    fn simplify_by_zeroing(&mut self) -> bool {
+        if self.phase != ShrinkPhase::SmartShrink {
+            return false;
+        }
+
+        while let Some((index, _)) = self.shrink_candidates.pop() {
+            // The candidates may be outdated, for example after an injection.
+            let byte = self.bytes.get(index).copied().unwrap_or_default();
+            if index >= self.next || byte == 0 {
+                continue;
+            }
+
+            self.bytes[index] = 0;
+            let Ok(simpler) = Self::gen_one_with_size(&self.bytes, self.next) else {
+                self.bytes[index] = byte;
+                continue;
+            };
+
+            self.prev = Some(core::mem::replace(&mut self.curr, simpler));
+            self.prev_next = Some(self.next);
+            self.zeroed = Some((index, byte));
+
+            return true;
+        }
+
+        self.phase = ShrinkPhase::Truncating;
+        false
+    }
+
+    /// Generates a simpler value from a shorter prefix, using binary search.
+    fn simplify_by_truncating(&mut self) -> bool {
+        // No longer prefix needs to be considered.
+        self.high = self.next;
+
+        while self.low < self.high {
+            let mid = self.low + (self.high - self.low) / 2;
+            let Ok(simpler) = Self::gen_one_with_size(&self.bytes, mid) else {
+                // Prefixes the `Arbitrary` impl rejects can never reproduce the
+                // failure.
+                self.low = mid + 1;
+                continue;
+            };
+
+            // Throw away the previous value and set the current value as prev.
+            // Set the current value to the simpler one.
+            self.prev = Some(core::mem::replace(&mut self.curr, simpler));
+            self.prev_next = Some(core::mem::replace(&mut self.next, mid));
+
+            return true;
+        }
+
+        false
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<A: ArbInterop> ArbValueTree<A> {
+    /// Saves the complete state of this tree as JSON, including the random
+    /// bytes and the progress of shrinking.
+    ///
+    /// Unlike proptest's own failure persistence, which only stores enough to
+    /// regenerate the value, this allows resuming shrinking exactly where it
+    /// stopped. See [`load_from_file`](Self::load_from_file).
+    pub fn save_to_file(&self, path: &std::path::Path) -> Result<(), std::io::Error>
+    where
+        A: serde::Serialize,
+    {
+        let file = std::io::BufWriter::new(std::fs::File::create(path)?);
+        serde_json::to_writer(file, self)?;
+
+        Ok(())
+    }
+
+    /// Loads a tree previously saved with [`save_to_file`](Self::save_to_file).
+    pub fn load_from_file(path: &std::path::Path) -> Result<Self, std::io::Error>
+    where
+        A: serde::de::DeserializeOwned,
+    {
+        let file = std::io::BufReader::new(std::fs::File::open(path)?);
+
+        Ok(serde_json::from_reader(file)?)
+    }
 }
 
 impl<A: ArbInterop> ArbStrategy<A> {
     pub fn new(size: usize) -> Self {
+        Self::with_config(ArbConfig::builder().size(size).build())
+    }
+
+    /// Like [`new`](Self::new), but identifies the strategy by the given `name`
+    /// instead of the name of type `A` in failure messages.
+    pub fn new_named(size: usize, name: &'static str) -> Self {
+        Self {
+            name,
+            ..Self::new(size)
+        }
+    }
+
+    /// Constructs a strategy that, for every test case, feeds a number of bytes
+    /// sampled uniformly from the inclusive range `[min, max]` to the
+    /// [`arbitrary::Arbitrary`] type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min` is greater than `max`.
+    pub fn with_size_range(min: usize, max: usize) -> Self {
+        Self::with_config(ArbConfig::builder().size_range(min, max).build())
+    }
+
+    /// Constructs a strategy tuned according to the given [`ArbConfig`].
+    pub fn with_config(config: ArbConfig) -> Self {
         Self {
-            size,
+            config,
+            name: core::any::type_name::<A>(),
+            location: None,
+            shrinker: SharedShrinker::default(),
             _ph: PhantomData,
         }
     }
+
+    /// Caps the number of bytes of random data at `max`, for example for types
+    /// whose [`size_hint`](arbitrary::Arbitrary::size_hint) would make [`arb`]
+    /// allocate huge buffers. Applies to every size the strategy may use.
+    pub fn with_max_size(mut self, max: usize) -> Self {
+        self.config.size_mode = self.config.size_mode.map_sizes(|size| size.min(max));
+        self
+    }
+
+    /// Raises the number of bytes of random data to at least `min`. Applies to
+    /// every size the strategy may use.
+    pub fn with_min_size(mut self, min: usize) -> Self {
+        self.config.size_mode = self.config.size_mode.map_sizes(|size| size.max(min));
+        self
+    }
+
+    /// Multiplies every size the strategy may use by `factor`, rounding to the
+    /// nearest number of bytes, but to at least one byte. For example,
+    /// `arb::<T>().scale_size(0.5)` feeds half the random data [`arb`] would.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `factor` is not positive or is NaN.
+    #[inline]
+    pub fn scale_size(mut self, factor: f64) -> Self {
+        assert!(factor > 0.0, "size factor must be positive, got {factor}");
+
+        // `f64::round` requires `std`. Sizes are never negative, so adding one
+        // half before truncating rounds to nearest, and `as` saturates.
+        let scale = |size| ((size as f64 * factor + 0.5) as usize).max(1);
+        self.config.size_mode = self.config.size_mode.map_sizes(scale);
+        self
+    }
+
+    /// The number of bytes of random data fed to the [`arbitrary::Arbitrary`]
+    /// implementation. If the number varies per test case, this is the
+    /// maximum.
+    pub fn buffer_size(&self) -> usize {
+        self.max_size()
+    }
+
+    /// The fewest bytes of random data fed to the [`arbitrary::Arbitrary`]
+    /// implementation in any test case.
+    pub const fn min_size(&self) -> usize {
+        match self.config.size_mode {
+            SizeMode::Fixed(size) | SizeMode::Range(size, _) => size,
+            SizeMode::Bimodal {
+                base, recursive, ..
+            } => {
+                if base < recursive {
+                    base
+                } else {
+                    recursive
+                }
+            }
+        }
+    }
+
+    /// The most bytes of random data fed to the [`arbitrary::Arbitrary`]
+    /// implementation in any test case. Same as
+    /// [`buffer_size`](Self::buffer_size).
+    pub const fn max_size(&self) -> usize {
+        match self.config.size_mode {
+            SizeMode::Fixed(size) | SizeMode::Range(_, size) => size,
+            SizeMode::Bimodal {
+                base, recursive, ..
+            } => {
+                if base < recursive {
+                    recursive
+                } else {
+                    base
+                }
+            }
+        }
+    }
+
+    /// Includes the random data in hexadecimal in the [`Debug`] output of the
+    /// generated value trees, see [`ArbValueTree::bytes_as_hex`]. The feature
+    /// `verbose-bytes` does this for all strategies.
+    pub fn with_verbose_bytes(mut self) -> Self {
+        self.config.verbose_bytes = true;
+        self
+    }
+
+    /// Gives up generating a value after the [`arbitrary::Arbitrary`]
+    /// implementation rejected random data `budget` times, failing the test
+    /// instead of retrying further. Sets
+    /// [`max_retries`](ArbConfig::max_retries).
+    ///
+    /// Every rejection also counts against the
+    /// [`max_local_rejects`](proptest::test_runner::Config::max_local_rejects)
+    /// of the [`TestRunner`], which all strategies of a test share. A small
+    /// budget keeps an implementation that rejects a lot from exhausting it
+    /// for the other strategies.
+    pub fn with_retry_budget(mut self, budget: u32) -> Self {
+        self.config.max_retries = budget;
+        self
+    }
+
+    /// Limits this strategy to `n` test cases. See [`Self::max_cases`].
+    pub fn cases(mut self, n: u32) -> Self {
+        self.config.max_cases = Some(n);
+        self
+    }
+
+    /// The number of test cases this strategy is meant for, if limited.
+    ///
+    /// The limit is advisory only, since proptest has no notion of per-strategy
+    /// limits. Respect it by configuring the test runner accordingly:
+    ///
+    /// ```
+    /// # use proptest::prelude::*;
+    /// # use proptest_arbitrary_adapter::arb;
+    /// let strategy = arb::<u64>().cases(10);
+    /// let config = ProptestConfig::with_cases(strategy.max_cases().unwrap_or(256));
+    ///
+    /// proptest!(config, |(n in strategy)| {
+    ///     prop_assert_eq!(n.count_ones(), (!n).count_zeros());
+    /// });
+    /// ```
+    pub fn max_cases(&self) -> Option<u32> {
+        self.config.max_cases
+    }
+
+    /// The name identifying the strategy in failure messages.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Records where the strategy was created, usually passing [`file!`] and
+    /// [`line!`], so that failure messages point there instead of to the helper
+    /// function that constructed it. See also [`arb_here!`].
+    pub fn with_source_location(self, file: &'static str, line: u32) -> Self {
+        Self {
+            location: Some((file, line)),
+            ..self
+        }
+    }
+
+    /// The file and line recorded by
+    /// [`with_source_location`](Self::with_source_location), if any.
+    pub fn source_location(&self) -> Option<(&'static str, u32)> {
+        self.location
+    }
+
+    /// Sets the [`source_file`](Config::source_file) of the given runner
+    /// `config` to the recorded file, if any, so that proptest names
+    /// regression files after it.
+    pub fn regression_config(&self, config: Config) -> Config {
+        match self.location {
+            Some((file, _)) => Config {
+                source_file: Some(file),
+                ..config
+            },
+            None => config,
+        }
+    }
+
+    /// Identifies the strategy in failure messages: its name, followed by its
+    /// source location, if known.
+    fn origin(&self) -> String {
+        match self.location {
+            Some((file, line)) => format!("{} ({file}:{line})", self.name),
+            None => self.name.into(),
+        }
+    }
+
+    /// Maps generated values to a related type, rejecting those for which `f`
+    /// returns [`None`].
+    ///
+    /// Same as [`Strategy::prop_filter_map`], but without the need to import
+    /// [`Strategy`] or to specify a reason for rejection. The returned strategy
+    /// has a nameable type.
+    pub fn filter_map<B, F>(self, f: F) -> FilterMap<Self, F>
+    where
+        B: Debug,
+        F: Fn(A) -> Option<B>,
+    {
+        self.prop_filter_map("ArbStrategy::filter_map", f)
+    }
+
+    /// Rejects generated values for which `invariant` returns `false`, for
+    /// invariants that belong to the generation contract rather than to a
+    /// single test, where [`prop_assume!`](proptest::prop_assume) would go.
+    ///
+    /// Same as [`Strategy::prop_filter`], but without the need to import
+    /// [`Strategy`] or to specify a reason for rejection. Rejections count
+    /// against the [`TestRunner`]'s budget, and shrinking skips values that
+    /// violate the invariant. The returned strategy has a nameable type.
+    pub fn prop_assume_arb<F: Fn(&A) -> bool>(self, invariant: F) -> Filter<Self, F> {
+        self.prop_filter("ArbStrategy::prop_assume_arb", invariant)
+    }
+
+    /// Generates exactly `n` values, each from its own random data, for
+    /// example a log of commands for a stateful type.
+    ///
+    /// Unlike with [`arb_vec`], the length never shrinks. Each element shrinks
+    /// on its own, in order.
+    pub fn repeat(self, n: usize) -> Vec<Self> {
+        vec![self; n]
+    }
+
+    /// Generates a value of type `B` with the strategy that `f` derives from a
+    /// generated value of type `A`, for example to size the random data of `B`
+    /// depending on `A`.
+    ///
+    /// Same as [`Strategy::prop_flat_map`], restricted to strategies of this
+    /// crate. Shrinking first simplifies `B`, then `A`.
+    pub fn and_then<B, F>(self, f: F) -> impl Strategy<Value = B>
+    where
+        B: ArbInterop,
+        F: Fn(A) -> ArbStrategy<B>,
+    {
+        self.prop_flat_map_arb(f)
+    }
+
+    /// Like [`Self::and_then`], but the returned strategy has a nameable type,
+    /// so it can be stored without boxing.
+    ///
+    /// ```
+    /// # use proptest::strategy::Flatten;
+    /// # use proptest::strategy::Map;
+    /// # use proptest_arbitrary_adapter::ArbStrategy;
+    /// # use proptest_arbitrary_adapter::arb;
+    /// # use proptest_arbitrary_adapter::arb_sized;
+    /// type Dependent = Flatten<Map<ArbStrategy<u8>, fn(u8) -> ArbStrategy<Vec<u8>>>>;
+    ///
+    /// let size_to_strategy: fn(u8) -> ArbStrategy<Vec<u8>> = |size| arb_sized(size.into());
+    /// let strategy: Dependent = arb::<u8>().prop_flat_map_arb(size_to_strategy);
+    /// ```
+    pub fn prop_flat_map_arb<B, F>(self, f: F) -> Flatten<Map<Self, F>>
+    where
+        B: ArbInterop,
+        F: Fn(A) -> ArbStrategy<B>,
+    {
+        self.prop_flat_map(f)
+    }
+
+    /// Generates pairs of a value and a related value that `f` derives from
+    /// it using additional randomness, for example an input and a slightly
+    /// modified copy of it.
+    ///
+    /// ```
+    /// # use proptest::prelude::RngCore;
+    /// # use proptest_arbitrary_adapter::arb;
+    /// let strategy = arb::<u32>().prop_perturb_arb(|a, rng| a ^ (1 << (rng.next_u32() % 32)));
+    /// ```
+    ///
+    /// Same as [`Strategy::prop_perturb`], but keeping the original value and
+    /// without exposing the [`TestRng`]. Shrinking simplifies the original
+    /// value and derives the related one from it anew, using the same
+    /// randomness.
+    pub fn prop_perturb_arb<B, F>(self, f: F) -> impl Strategy<Value = (A, B)>
+    where
+        B: Debug,
+        F: Fn(A, &mut dyn RngCore) -> B + 'static,
+    {
+        self.prop_perturb(move |a, mut rng| {
+            let b = f(a.clone(), &mut rng);
+            (a, b)
+        })
+    }
 }
 
 impl<A: ArbInterop> ArbValueTree<A> {
@@ -120,15 +1012,184 @@ impl<A: ArbInterop> ArbValueTree<A> {
     }
 
     pub fn new(bytes: Vec<u8>) -> Result<Self, arbitrary::Error> {
+        let curr = Self::gen_one_with_size(&bytes, bytes.len())?;
+
+        Ok(Self::with_value(bytes, curr))
+    }
+
+    /// Like [`Self::new`], but also returns how many of the `bytes` the
+    /// [`arbitrary::Arbitrary`] implementation actually consumed, without
+    /// generating the value twice like [`bytes_consumed`](Self::bytes_consumed)
+    /// does.
+    pub fn new_with_stats(bytes: Vec<u8>) -> Result<(Self, usize), arbitrary::Error> {
+        let mut u = arbitrary::Unstructured::new(&bytes);
+        let curr = A::arbitrary(&mut u)?;
+        let consumed = bytes.len() - u.len();
+
+        Ok((Self::with_value(bytes, curr), consumed))
+    }
+
+    /// Like [`Self::new`], for a value `curr` already generated from `bytes`.
+    fn with_value(bytes: impl Into<Bytes>, curr: A) -> Self {
+        let bytes = bytes.into();
         let next = bytes.len();
-        let curr = Self::gen_one_with_size(&bytes, next)?;
+        let shrink_candidates = Self::shrink_candidates(&bytes);
 
-        Ok(Self {
+        Self {
             bytes,
             prev: None,
             curr,
             next,
-        })
+            prev_next: None,
+            low: 0,
+            high: next,
+            steps_left: usize::MAX,
+            phase: ShrinkPhase::SmartShrink,
+            shrink_candidates,
+            zeroed: None,
+            injected: None,
+            label: None,
+            verbose_bytes: false,
+            shrinker: None,
+            simplify_steps: 0,
+            complicate_steps: 0,
+        }
+    }
+
+    /// Replaces the first bytes of the random data with `new_bytes`, and the
+    /// current value with the one generated from them. Useful for external
+    /// minimizers that steer shrinking towards specific byte patterns.
+    ///
+    /// Like a simplification, the injection can be undone by
+    /// [`complicate`](ValueTree::complicate), and shrinking continues from the
+    /// new bytes otherwise. If the [`arbitrary::Arbitrary`] implementation
+    /// rejects `new_bytes`, the tree is left unchanged.
+    pub fn inject_bytes(&mut self, new_bytes: &[u8]) -> Result<(), arbitrary::Error> {
+        let curr = Self::gen_one_with_size(new_bytes, new_bytes.len())?;
+
+        let mut bytes = self.bytes.clone();
+        if bytes.len() < new_bytes.len() {
+            bytes.resize(new_bytes.len(), 0);
+        }
+        bytes[..new_bytes.len()].copy_from_slice(new_bytes);
+
+        self.injected = Some(core::mem::replace(&mut self.bytes, bytes));
+        self.prev = Some(core::mem::replace(&mut self.curr, curr));
+        self.prev_next = Some(core::mem::replace(&mut self.next, new_bytes.len()));
+        self.zeroed = None;
+        self.low = 0;
+        self.high = self.next;
+        self.phase = ShrinkPhase::SmartShrink;
+        self.shrink_candidates = Self::shrink_candidates(&self.bytes[..self.next]);
+
+        Ok(())
+    }
+
+    /// Constructs a tree from previously recorded bytes, for example those
+    /// obtained from [`raw_bytes`](Self::raw_bytes) of a failing test case.
+    ///
+    /// The same bytes always lead to the same value, which makes it possible to
+    /// deterministically replay a failure.
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<Self, arbitrary::Error> {
+        Self::new(bytes)
+    }
+
+    /// The random bytes this tree generates its values from. Shrinking might
+    /// zero some of them.
+    ///
+    /// The [current](ValueTree::current) value is generated from the first
+    /// [`current_size`](Self::current_size) bytes only. To replay it, pass that
+    /// prefix to [`from_bytes`](Self::from_bytes).
+    pub fn raw_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Attaches the given `label` to the tree, which its [`Debug`] and
+    /// [`Display`] output include. Replaces any previous label.
+    ///
+    /// Proptest reports only the failing value, not the tree, so the label is
+    /// meant for code inspecting trees directly. See also
+    /// [`ArbStrategy::annotate`].
+    pub fn annotate(&mut self, label: impl Into<String>) {
+        self.label = Some(label.into());
+    }
+
+    /// The label attached by [`Self::annotate`], if any.
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    /// The length of the prefix of [`raw_bytes`](Self::raw_bytes) that the
+    /// [current](ValueTree::current) value is generated from.
+    pub fn current_size(&self) -> usize {
+        self.next
+    }
+
+    /// How many bytes of the [current size](Self::current_size) the
+    /// [`arbitrary::Arbitrary`] implementation actually reads to generate the
+    /// [current](ValueTree::current) value, as a measure of its complexity.
+    ///
+    /// Generates the value anew to find out.
+    pub fn bytes_consumed(&self) -> usize {
+        let mut u = arbitrary::Unstructured::new(&self.bytes[..self.next]);
+        let _ = A::arbitrary(&mut u);
+        self.next - u.len()
+    }
+
+    /// All values that repeated [simplification](ValueTree::simplify) would
+    /// produce, in order, assuming each of them reproduces the failure.
+    ///
+    /// Does not modify `self`. Useful to verify that an
+    /// [`arbitrary::Arbitrary`] implementation shrinks to the expected minimal
+    /// value.
+    #[cfg(any(test, feature = "debug-shrink"))]
+    pub fn shrink_path(&self) -> Vec<A> {
+        let mut tree = self.clone();
+        let mut path = vec![];
+        while tree.simplify() {
+            path.push(tree.current());
+        }
+
+        path
+    }
+}
+
+/// A [`proptest::strategy::ValueTree`] of the value generated from all-zero
+/// bytes. The value is considered minimal, and so can neither be simplified nor
+/// complicated. See [`ArbStrategy::minimal`].
+#[derive(Debug, Clone)]
+pub struct ZeroArbValueTree<A: Debug> {
+    value: A,
+}
+
+impl<A: ArbInterop> ValueTree for ZeroArbValueTree<A> {
+    type Value = A;
+
+    fn current(&self) -> Self::Value {
+        self.value.clone()
+    }
+
+    fn simplify(&mut self) -> bool {
+        false
+    }
+
+    fn complicate(&mut self) -> bool {
+        false
+    }
+}
+
+impl<A: ArbInterop> ArbStrategy<A> {
+    /// Generates the value from the minimal number of all-zero bytes, according
+    /// to the lower bound of `A`'s [`size_hint`](arbitrary::Arbitrary::size_hint).
+    ///
+    /// Useful to check that the [`arbitrary::Arbitrary`] implementation
+    /// gracefully handles zero bytes.
+    pub fn minimal() -> Result<ZeroArbValueTree<A>, arbitrary::Error> {
+        let (min_size, _) = A::size_hint(0);
+        let bytes = vec![0; min_size];
+        let value = A::arbitrary(&mut arbitrary::Unstructured::new(&bytes))?;
+
+        Ok(ZeroArbValueTree { value })
     }
 }
 
@@ -137,26 +1198,339 @@ impl<A: ArbInterop> proptest::strategy::Strategy for ArbStrategy<A> {
     type Value = A;
 
     fn new_tree(&self, run: &mut TestRunner) -> proptest::strategy::NewTree<Self> {
+        self.new_tree_with(run, |run, bytes| run.rng().fill_bytes(bytes))
+    }
+}
+
+impl<A: ArbInterop> ArbStrategy<A> {
+    /// Like [`Strategy::new_tree`], but uses `fill_bytes` to produce the bytes
+    /// that are fed to the [`arbitrary::Arbitrary`] implementation. The passed
+    /// buffer is zeroed and has the sampled size, but it may be resized.
+    pub(crate) fn new_tree_with(
+        &self,
+        run: &mut TestRunner,
+        fill_bytes: impl FnMut(&mut TestRunner, &mut Vec<u8>),
+    ) -> proptest::strategy::NewTree<Self> {
+        self.new_tree_observed(run, fill_bytes, || ())
+    }
+
+    /// Like [`Self::new_tree_with`], but calls `on_rejection` whenever the
+    /// random data is rejected.
+    pub(crate) fn new_tree_observed(
+        &self,
+        run: &mut TestRunner,
+        fill_bytes: impl FnMut(&mut TestRunner, &mut Vec<u8>),
+        on_rejection: impl FnMut(),
+    ) -> proptest::strategy::NewTree<Self> {
+        let size_mode = self.config.size_mode;
+        self.new_tree_sized(
+            run,
+            |run, _| size_mode.sample(run),
+            fill_bytes,
+            on_rejection,
+            ArbValueTree::new,
+        )
+    }
+
+    /// Like [`Self::new_tree_observed`], but with the sizes and trees of
+    /// [`Self::generate_sized`].
+    pub(crate) fn new_tree_sized(
+        &self,
+        run: &mut TestRunner,
+        sample_size: impl FnMut(&mut TestRunner, u32) -> Result<usize, Reason>,
+        fill_bytes: impl FnMut(&mut TestRunner, &mut Vec<u8>),
+        on_rejection: impl FnMut(),
+        build: impl Fn(Vec<u8>) -> Result<ArbValueTree<A>, arbitrary::Error>,
+    ) -> proptest::strategy::NewTree<Self> {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!(
+            "new_tree",
+            type_name = core::any::type_name::<A>(),
+            buffer_size = self.buffer_size(),
+        )
+        .entered();
+
+        let generated = self.generate_sized(run, sample_size, fill_bytes, on_rejection, build);
+
+        #[cfg(feature = "tracing")]
+        match &generated {
+            Ok(tree) => tracing::debug!(next = tree.next, succeeded = true),
+            Err(reason) => tracing::debug!(%reason, succeeded = false),
+        }
+        let mut tree = generated?;
+        self.configure_tree(&mut tree);
+
+        Ok(tree)
+    }
+
+    /// Applies the shrinking configuration of this strategy to a freshly
+    /// generated tree.
+    pub(crate) fn configure_tree(&self, tree: &mut ArbValueTree<A>) {
+        tree.steps_left = self.config.shrink_max_steps;
+        tree.verbose_bytes = self.config.verbose_bytes;
+        if let SharedShrinker(Some(shrinker)) = &self.shrinker {
+            tree.set_shrink_strategy(Arc::clone(shrinker));
+        }
+    }
+
+    /// Builds something from random data, retrying as configured if the
+    /// [`arbitrary::Arbitrary`] implementation rejects the random data.
+    pub(crate) fn generate<T>(
+        &self,
+        run: &mut TestRunner,
+        fill_bytes: impl FnMut(&mut TestRunner, &mut Vec<u8>),
+        on_rejection: impl FnMut(),
+        build: impl Fn(Vec<u8>) -> Result<T, arbitrary::Error>,
+    ) -> Result<T, Reason> {
+        let size_mode = self.config.size_mode;
+        self.generate_sized(
+            run,
+            |run, _| size_mode.sample(run),
+            fill_bytes,
+            on_rejection,
+            build,
+        )
+    }
+
+    /// Like [`Self::generate`], but ignores the configured sizes. Instead,
+    /// `sample_size` picks the size of every buffer of random data, given the
+    /// number of rejections so far.
+    pub(crate) fn generate_sized<T>(
+        &self,
+        run: &mut TestRunner,
+        mut sample_size: impl FnMut(&mut TestRunner, u32) -> Result<usize, Reason>,
+        mut fill_bytes: impl FnMut(&mut TestRunner, &mut Vec<u8>),
+        mut on_rejection: impl FnMut(),
+        build: impl Fn(Vec<u8>) -> Result<T, arbitrary::Error>,
+    ) -> Result<T, Reason> {
+        let mut num_retries = 0;
         loop {
-            let mut bytes = vec![0; self.size];
-            run.rng().fill_bytes(&mut bytes);
-            match ArbValueTree::new(bytes) {
-                Ok(v) => return Ok(v),
+            let mut bytes = vec![0; sample_size(run, num_retries)?];
+            fill_bytes(run, &mut bytes);
+
+            // After the first rejection, further rejections are likely. Search
+            // for acceptable random data in parallel.
+            #[cfg(feature = "rayon")]
+            if num_retries > 0 {
+                let (rejections, acceptable) = self.search_in_parallel(
+                    run,
+                    |run, num_earlier| sample_size(run, num_retries + num_earlier),
+                    &mut fill_bytes,
+                    bytes,
+                )?;
+                for e in rejections {
+                    self.reject(run, e, &mut num_retries, &mut on_rejection)?;
+                }
+                let Some(acceptable) = acceptable else {
+                    continue;
+                };
+                bytes = acceptable;
+            }
+
+            match build(bytes) {
+                Ok(built) => return Ok(built),
 
                 // If the Arbitrary impl cannot construct a value from the given
                 // bytes, try again.
-                Err(e @ arbitrary::Error::IncorrectFormat) => run.reject_local(format!("{e}"))?,
-                Err(e) => return Err(format!("{e}").into()),
+                Err(e @ (arbitrary::Error::IncorrectFormat | arbitrary::Error::NotEnoughData)) => {
+                    self.reject(run, e, &mut num_retries, &mut on_rejection)?
+                }
+                Err(e) => return Err(format!("{}: {e}", self.origin()).into()),
             }
         }
     }
+
+    /// Records the rejection of random data, failing if there were too many.
+    fn reject(
+        &self,
+        run: &mut TestRunner,
+        e: arbitrary::Error,
+        num_retries: &mut u32,
+        on_rejection: &mut impl FnMut(),
+    ) -> Result<(), Reason> {
+        if *num_retries >= self.config.max_retries {
+            let name = self.origin();
+            return Err(format!("{name}: too many rejections generating value").into());
+        }
+        *num_retries += 1;
+        on_rejection();
+        run.reject_local(format!("{}: {e}", self.origin()))
+    }
 }
 
-/// Constructs a [`proptest::strategy::Strategy`] for a given
-/// [`arbitrary::Arbitrary`] type, generating `size` bytes of random data as
-/// input to the [`arbitrary::Arbitrary`] type.
-pub fn arb_sized<A: ArbInterop>(size: usize) -> ArbStrategy<A> {
-    ArbStrategy::new(size)
+impl<A: ArbInterop> Display for ArbStrategy<A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let type_name = core::any::type_name::<A>();
+        match self.config.size_mode {
+            SizeMode::Fixed(size) => write!(f, "ArbStrategy<{type_name}>(size={size})"),
+            SizeMode::Range(min, max) => {
+                write!(f, "ArbStrategy<{type_name}>(size={min}..={max})")
+            }
+            SizeMode::Bimodal {
+                base, recursive, ..
+            } => write!(f, "ArbStrategy<{type_name}>(size={base}|{recursive})"),
+        }
+    }
+}
+
+/// Generates a tree from the remaining bytes of an [`arbitrary::Unstructured`],
+/// for example one built from a fuzzer's input, to shrink the value with
+/// proptest. Like [`ArbValueTree::new`], but copies the bytes.
+impl<'a, A: ArbInterop> TryFrom<arbitrary::Unstructured<'a>> for ArbValueTree<A> {
+    type Error = arbitrary::Error;
+
+    fn try_from(u: arbitrary::Unstructured<'a>) -> Result<Self, Self::Error> {
+        Self::new(u.take_rest().to_vec())
+    }
+}
+
+impl<A: Debug> ArbValueTree<A> {
+    /// The prefix of [`raw_bytes`](Self::raw_bytes) that the
+    /// [current](ValueTree::current) value is generated from, in lowercase
+    /// hexadecimal, like `"deadbeef"`.
+    ///
+    /// Useful when the connection between the random data and the value
+    /// matters, for example for binary protocol parsers.
+    pub fn bytes_as_hex(&self) -> String {
+        self.bytes[..self.next]
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect()
+    }
+}
+
+impl<A: Debug> Debug for ArbValueTree<A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let Self {
+            bytes,
+            curr,
+            prev,
+            next,
+            prev_next,
+            low,
+            high,
+            steps_left,
+            simplify_steps,
+            complicate_steps,
+            phase,
+            shrink_candidates,
+            zeroed,
+            injected,
+            label,
+            verbose_bytes,
+            shrinker,
+        } = self;
+
+        let mut debug = f.debug_struct("ArbValueTree");
+        debug
+            .field("bytes", bytes)
+            .field("curr", curr)
+            .field("prev", prev)
+            .field("next", next)
+            .field("prev_next", prev_next)
+            .field("low", low)
+            .field("high", high)
+            .field("steps_left", steps_left)
+            .field("simplify_steps", simplify_steps)
+            .field("complicate_steps", complicate_steps)
+            .field("phase", phase)
+            .field("shrink_candidates", shrink_candidates)
+            .field("zeroed", zeroed)
+            .field("injected", injected)
+            .field("label", label)
+            .field("shrinker", shrinker);
+        if *verbose_bytes || cfg!(feature = "verbose-bytes") {
+            debug.field("bytes_as_hex", &self.bytes_as_hex());
+        }
+
+        debug.finish()
+    }
+}
+
+impl<A: Debug> Display for ArbValueTree<A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let Self {
+            bytes,
+            curr,
+            next,
+            label,
+            ..
+        } = self;
+        if let Some(label) = label {
+            write!(f, "{label}: ")?;
+        }
+        let len = bytes.len();
+        write!(f, "ArbValueTree {{ size: {next}/{len}, value: {curr:?} }}")
+    }
+}
+
+/// Trees are equal if their current values are generated from the same random
+/// data. The values themselves are not compared, since they are derived from
+/// the data, and neither is the progress of shrinking.
+impl<A: Debug> PartialEq for ArbValueTree<A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.next == other.next && self.bytes == other.bytes
+    }
+}
+
+impl<A: Debug> Eq for ArbValueTree<A> {}
+
+impl<A: Debug> Hash for ArbValueTree<A> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.bytes.hash(state);
+        self.next.hash(state);
+    }
+}
+
+/// A [`proptest::strategy::Strategy`] that always generates its value from the
+/// same bytes. See [`arb_with_bytes`].
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct FixedBytesArbStrategy<A: ArbInterop> {
+    bytes: Vec<u8>,
+    _ph: PhantomData<fn() -> A>,
+}
+
+impl<A: ArbInterop> proptest::strategy::Strategy for FixedBytesArbStrategy<A> {
+    type Tree = ArbValueTree<A>;
+    type Value = A;
+
+    fn new_tree(&self, _: &mut TestRunner) -> proptest::strategy::NewTree<Self> {
+        ArbValueTree::new(self.bytes.clone()).map_err(|e| format!("{e}").into())
+    }
+}
+
+/// Constructs a [`proptest::strategy::Strategy`] for a given
+/// [`arbitrary::Arbitrary`] type, generating `size` bytes of random data as
+/// input to the [`arbitrary::Arbitrary`] type.
+pub fn arb_sized<A: ArbInterop>(size: usize) -> ArbStrategy<A> {
+    ArbStrategy::new(size)
+}
+
+/// Like [`arb_sized`], but identifies the strategy by the given `name` instead
+/// of the name of type `A` in failure messages.
+pub fn arb_sized_named<A: ArbInterop>(size: usize, name: &'static str) -> ArbStrategy<A> {
+    ArbStrategy::new_named(size, name)
+}
+
+/// Constructs a [`proptest::strategy::Strategy`] for a given
+/// [`arbitrary::Arbitrary`] type that always uses the given `bytes` as input to
+/// the [`arbitrary::Arbitrary`] type, instead of generating random data.
+///
+/// Useful to deterministically replay a failure, for example from bytes
+/// recorded through [`ArbValueTree::raw_bytes`]. The generated value is still
+/// shrunk as usual.
+///
+/// Fails if the [`arbitrary::Arbitrary`] type cannot be constructed from the
+/// given `bytes`.
+pub fn arb_with_bytes<A: ArbInterop>(
+    bytes: Vec<u8>,
+) -> Result<FixedBytesArbStrategy<A>, arbitrary::Error> {
+    ArbValueTree::<A>::new(bytes.clone())?;
+
+    Ok(FixedBytesArbStrategy {
+        bytes,
+        _ph: PhantomData,
+    })
 }
 
 /// Constructs a [`proptest::strategy::Strategy`] for a given
@@ -168,39 +1542,1421 @@ pub fn arb_sized<A: ArbInterop>(size: usize) -> ArbStrategy<A> {
 /// In particular, if `A`'s [`size_hint`](arbitrary::Arbitrary::size_hint) is
 /// useful, the hint is used; otherwise, a default size of 256 is used.
 pub fn arb<A: ArbInterop>() -> ArbStrategy<A> {
-    let (low, opt_high) = A::size_hint(0);
+    arb_size_hint_at_depth(0)
+}
+
+/// Like [`arb`], but identifies the strategy by the given `name` instead of the
+/// name of type `A` in failure messages.
+pub fn arb_named<A: ArbInterop>(name: &'static str) -> ArbStrategy<A> {
+    ArbStrategy { name, ..arb() }
+}
+
+/// Constructs a [`proptest::strategy::Strategy`] for a given
+/// [`arbitrary::Arbitrary`] type, sized according to the type's
+/// [`size_hint`](arbitrary::Arbitrary::size_hint) at the given `depth`.
+///
+/// Recursive types, like the nodes of an abstract syntax tree, might report
+/// more accurate size hints at larger depths. Otherwise, this behaves exactly
+/// like [`arb`], which is the same as `arb_size_hint_at_depth(0)`.
+pub fn arb_size_hint_at_depth<A: ArbInterop>(depth: usize) -> ArbStrategy<A> {
+    let (low, opt_high) = A::size_hint(depth);
     let Some(high) = opt_high else {
-        let size_hint = (2 * low).max(256);
+        let size_hint = (2 * low).max(config::DEFAULT_SIZE);
         return arb_sized(size_hint);
     };
 
     arb_sized(high)
 }
 
+/// Constructs a [`proptest::strategy::Strategy`] for a given
+/// [`arbitrary::Arbitrary`] type, sized by probing the type if its
+/// [`size_hint`](arbitrary::Arbitrary::size_hint) has no upper bound.
+///
+/// Size hints do not account for bytes that the [`arbitrary::Arbitrary`]
+/// implementation only inspects, for example using
+/// [`peek_bytes`](arbitrary::Unstructured::peek_bytes). Probing finds the
+/// smallest number of all-zero bytes, out of 0 and the powers of two up to
+/// 1 MiB, from which a value can be generated. The strategy feeds twice that
+/// many bytes, but never fewer than [`arb`] would. Probing happens once, when
+/// constructing the strategy.
+///
+/// If the size hint has an upper bound, this behaves exactly like [`arb`].
+pub fn arb_probed<A: ArbInterop>() -> ArbStrategy<A> {
+    let (low, opt_high) = A::size_hint(0);
+    if opt_high.is_some() {
+        return arb();
+    }
+
+    let fallback = (2 * low).max(config::DEFAULT_SIZE);
+    let size = probe_size::<A>().map_or(fallback, |size| (2 * size).max(fallback));
+
+    arb_sized(size)
+}
+
+/// The smallest number of all-zero bytes, out of 0 and the powers of two up to
+/// 1 MiB, from which a value of the given type can be generated.
+fn probe_size<A: ArbInterop>() -> Option<usize> {
+    const MAX_EXPONENT: u32 = 20;
+
+    let mut candidates =
+        core::iter::once(0).chain((0..=MAX_EXPONENT).map(|exponent| 1 << exponent));
+
+    // Grow the buffer as the probe advances, so that types accepting few bytes
+    // never pay for the largest candidate.
+    let mut zeros = Vec::new();
+    candidates.find(|&size| {
+        zeros.resize(size, 0);
+        A::arbitrary(&mut arbitrary::Unstructured::new(&zeros)).is_ok()
+    })
+}
+
+/// Constructs a tuple of [`ArbStrategy`]s, one for each of the given
+/// [`arbitrary::Arbitrary`] types. The tuple itself is a
+/// [`proptest::strategy::Strategy`] for tuples of the given types.
+///
+/// Every element is constructed using [`arb`] independently, _i.e._, sized
+/// according to its own type. Like proptest's tuple strategies, up to 12 types
+/// are supported.
+///
+/// ```rust
+/// # use proptest_arbitrary_adapter::arb_tuple;
+/// # use test_strategy::proptest;
+/// #[proptest]
+/// fn my_test(#[strategy(arb_tuple!(u8, bool, char))] tuple: (u8, bool, char)) {
+///     // …
+/// }
+/// ```
+#[macro_export]
+macro_rules! arb_tuple {
+    ($($ty:ty),+ $(,)?) => {
+        ($($crate::arb::<$ty>(),)+)
+    };
+}
+
+/// Like [`arb`], but records the location of the macro call via
+/// [`ArbStrategy::with_source_location`], for failure messages to point to.
+///
+/// ```rust
+/// # use proptest_arbitrary_adapter::ArbStrategy;
+/// # use proptest_arbitrary_adapter::arb_here;
+/// let strategy: ArbStrategy<u8> = arb_here!();
+/// assert_eq!(Some((file!(), line!() - 1)), strategy.source_location());
+/// ```
+#[macro_export]
+macro_rules! arb_here {
+    () => {
+        $crate::arb().with_source_location(file!(), line!())
+    };
+}
+
+/// Constructs a [`proptest::strategy::Strategy`] for a given recursive
+/// [`arbitrary::Arbitrary`] type, like the nodes of an abstract syntax tree.
+///
+/// For every test case, either `base_size` or `recursive_size` bytes of random
+/// data are used, giving a mix of shallow and deep values. The larger the lower
+/// bound of `A`'s [`size_hint`](arbitrary::Arbitrary::size_hint) compared to
+/// `base_size`, the more likely `recursive_size` is chosen, but at least a
+/// quarter and at most three quarters of the test cases use it.
+pub fn arb_recursive<A: ArbInterop>(base_size: usize, recursive_size: usize) -> ArbStrategy<A> {
+    let (low, _) = A::size_hint(0);
+    let recursive_per_mille = (low.saturating_mul(1000) / base_size.max(1)).clamp(250, 750);
+    let size_mode = SizeMode::Bimodal {
+        base: base_size,
+        recursive: recursive_size,
+        recursive_per_mille: recursive_per_mille.try_into().unwrap_or(750),
+    };
+
+    ArbStrategy::with_config(ArbConfig::builder().size_mode(size_mode).build())
+}
+
+/// Constructs a [`proptest::strategy::Strategy`] for a given recursive
+/// [`arbitrary::Arbitrary`] type, sizing the random data like proptest's
+/// [`prop_recursive`](Strategy::prop_recursive) would build a tree.
+///
+/// The size is that of a tree up to `max_depth` levels deep, where every leaf
+/// contributes `base_size` bytes, and every inner node contributes
+/// `recursive_size` bytes plus those of its 1 to `max_breadth` children. Unlike
+/// [`arb_recursive`], which picks one of two sizes, this gives a spread of
+/// sizes, and shrinking simplifies the tree of sizes first.
+pub fn arb_recursive_strategy<A: ArbInterop>(
+    base_size: usize,
+    recursive_size: usize,
+    max_depth: u32,
+    max_breadth: u32,
+) -> impl Strategy<Value = A> {
+    let max_breadth = max_breadth.max(1);
+    let desired_size = max_breadth.saturating_pow(max_depth);
+    let recurse = move |children| {
+        proptest::collection::vec(children, 1..=max_breadth as usize).prop_map(move |sizes| {
+            sizes
+                .into_iter()
+                .fold(recursive_size, usize::saturating_add)
+        })
+    };
+
+    Just(base_size)
+        .prop_recursive(max_depth, desired_size, max_breadth, recurse)
+        .prop_flat_map(arb_sized)
+}
+
+/// Constructs a [`proptest::strategy::Strategy`] for a given
+/// [`arbitrary::Arbitrary`] type `B`, whose strategy `f` derives from a value of
+/// type `A` generated like [`arb`] does. See [`ArbStrategy::and_then`].
+pub fn arb_and_then<A, B, F>(f: F) -> impl Strategy<Value = B>
+where
+    A: ArbInterop,
+    B: ArbInterop,
+    F: Fn(A) -> ArbStrategy<B>,
+{
+    arb::<A>().and_then(f)
+}
+
+/// Constructs a [`proptest::strategy::Strategy`] for [`Vec`]s of a given
+/// [`arbitrary::Arbitrary`] type, with a length drawn from the given `length`
+/// strategy. Every element is generated like [`arb`] does.
+///
+/// Shrinking does not change the length, only the elements.
+pub fn arb_vec<A: ArbInterop>(
+    length: impl Strategy<Value = usize>,
+) -> impl Strategy<Value = Vec<A>> {
+    length.prop_flat_map(|n| proptest::collection::vec(arb::<A>(), n..=n))
+}
+
+/// Constructs a [`proptest::strategy::Strategy`] for [`Vec`]s of exactly `n`
+/// elements of a given [`arbitrary::Arbitrary`] type. See [`arb_vec`].
+pub fn arb_vec_of_size<A: ArbInterop>(n: usize) -> impl Strategy<Value = Vec<A>> {
+    arb_vec(Just(n))
+}
+
+/// Constructs a [`proptest::strategy::Strategy`] that always generates the
+/// given `value`, which never shrinks. Unlike the other strategies of this
+/// crate, `A` does not need to implement [`arbitrary::Arbitrary`].
+///
+/// This is [`Just`] under the hood. The concrete type is deliberately hidden,
+/// so that the strategy can shrink in the future without a breaking change.
+pub fn arb_just<A: Clone + Debug>(value: A) -> impl Strategy<Value = A> {
+    Just(value)
+}
+
+/// The raw [`size_hint`](arbitrary::Arbitrary::size_hint) of a given
+/// [`arbitrary::Arbitrary`] type, which [`arb`] bases its size on.
+///
+/// Useful to debug why [`arb`] generates unexpectedly small or large values.
+/// See also [`ArbStrategy::buffer_size`].
+pub fn size_hint_bytes<A: ArbInterop>() -> (usize, Option<usize>) {
+    A::size_hint(0)
+}
+
+/// Generates a single value of a given [`arbitrary::Arbitrary`] type using
+/// [`arb`], outside of any property test.
+///
+/// Useful, for example, in the setup code of a benchmark.
+pub fn arb_one<A: ArbInterop>() -> Result<A, TestError<A>> {
+    generate_one(&mut TestRunner::default())
+}
+
+/// Like [`arb_one`], but deterministic: the same `seed` always leads to the
+/// same value.
+pub fn arb_one_seeded<A: ArbInterop>(seed: u64) -> Result<A, TestError<A>> {
+    let mut rng_seed = [0; 32];
+    rng_seed[..8].copy_from_slice(&seed.to_le_bytes());
+    let rng = TestRng::from_seed(RngAlgorithm::ChaCha, &rng_seed);
+
+    generate_one(&mut TestRunner::new_with_rng(Config::default(), rng))
+}
+
+/// Warns about a likely mistake in a test: as a `tracing` event with the
+/// feature `tracing`, and on standard error otherwise.
+pub(crate) fn warn(message: core::fmt::Arguments<'_>) {
+    #[cfg(feature = "tracing")]
+    tracing::warn!("{message}");
+
+    #[cfg(all(feature = "std", not(feature = "tracing")))]
+    std::eprintln!("{message}");
+
+    #[cfg(not(any(feature = "std", feature = "tracing")))]
+    let _ = message;
+}
+
+/// Panics unless `probability` is in the range `[0.0, 1.0]`.
+#[track_caller]
+pub(crate) fn assert_probability(probability: f64) {
+    assert!(
+        (0.0..=1.0).contains(&probability),
+        "probability {probability} must be in range [0.0, 1.0]"
+    );
+}
+
+/// Locks the given mutex, ignoring poisoning: a test case that panicked while
+/// holding the lock leaves the shared state consistent enough for the next.
+#[cfg(feature = "std")]
+pub(crate) fn lock<T>(mutex: &std::sync::Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// A random number in the range `[0, bound)`. The bound must not be zero.
+pub(crate) fn random_below(run: &mut TestRunner, bound: usize) -> usize {
+    (run.rng().next_u64() % bound as u64) as usize
+}
+
+fn generate_one<A: ArbInterop>(run: &mut TestRunner) -> Result<A, TestError<A>> {
+    let tree = arb::<A>().new_tree(run).map_err(TestError::Abort)?;
+
+    Ok(tree.current())
+}
+
 #[cfg(test)]
 #[cfg_attr(coverage_nightly, feature(coverage_attribute))]
 mod tests {
+    use core::num::NonZeroU8;
+
     use arbitrary::Arbitrary;
     use proptest::prelude::*;
     use test_strategy::proptest;
 
     use super::*;
+    use crate::test_util::Len;
 
     #[derive(Debug, Clone, Arbitrary)]
     struct Test(u8);
 
+    /// Neither [`Send`] nor [`Sync`].
+    #[derive(Debug, Clone)]
+    struct NotThreadSafe(PhantomData<*const ()>);
+
+    impl<'a> Arbitrary<'a> for NotThreadSafe {
+        fn arbitrary(_: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+            Ok(Self(PhantomData))
+        }
+    }
+
+    static_assertions::assert_impl_all!(ArbStrategy<u8>: Send, Sync);
+    static_assertions::assert_impl_all!(ArbStrategy<NotThreadSafe>: Send, Sync);
+    static_assertions::assert_impl_all!(FixedBytesArbStrategy<NotThreadSafe>: Send, Sync);
+    static_assertions::assert_impl_all!(GrowingArbStrategy<NotThreadSafe>: Send, Sync);
+    static_assertions::assert_impl_all!(ArbValueTree<u8>: Send, Sync);
+    static_assertions::assert_not_impl_any!(ArbValueTree<NotThreadSafe>: Send, Sync);
+
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Arbitrary)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    struct Rgb {
+        r: u8,
+        g: u8,
+        b: u8,
+    }
+
+    /// Requires at least 8 bytes to be generated from.
+    #[derive(Debug, Clone)]
+    struct AtLeast8(usize);
+
+    impl<'a> Arbitrary<'a> for AtLeast8 {
+        fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+            if u.len() < 8 {
+                return Err(arbitrary::Error::NotEnoughData);
+            }
+
+            Ok(Self(u.len()))
+        }
+
+        fn size_hint(_: usize) -> (usize, Option<usize>) {
+            (8, None)
+        }
+    }
+
+    /// Shrinks the given tree the way proptest's test runner does, returning
+    /// the minimal failing value and the number of shrinking steps taken.
+    fn shrink<A: ArbInterop>(mut tree: ArbValueTree<A>, fails: impl Fn(&A) -> bool) -> (A, usize) {
+        let mut minimal = tree.current();
+        let mut num_steps = 0;
+        let mut can_shrink = tree.simplify();
+        while can_shrink {
+            num_steps += 1;
+            let candidate = tree.current();
+            if fails(&candidate) {
+                minimal = candidate;
+                can_shrink = tree.simplify();
+            } else {
+                can_shrink = tree.complicate();
+            }
+        }
+
+        (minimal, num_steps)
+    }
+
     #[proptest(cases = 1)]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
     fn type_can_be_generated(#[strategy(arb())] test: Test) {
         let Test(_t) = test;
     }
 
-    // As far as I know, `wasm_bindgen_test` does not support  the
-    // `#[should_panic]` attribute:
-    // https://github.com/wasm-bindgen/wasm-bindgen/issues/2286
-    #[should_panic]
-    #[proptest(cases = 1)]
-    fn type_can_shrink(#[strategy(arb())] _test: Test) {
-        Err(TestCaseError::Fail("always".into()))?;
+    // Not `#[should_panic]`: `#[proptest]` hides the attribute from
+    // `wasm_bindgen_test`, which then counts the panic as a failure.
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn type_can_shrink() {
+        let mut runner = TestRunner::new(ProptestConfig::with_cases(1));
+        let result = runner.run(&arb::<Test>(), |_| Err(TestCaseError::fail("always")));
+
+        let Err(TestError::Fail(_, minimal)) = result else {
+            panic!("test case did not fail: {result:?}");
+        };
+        assert_eq!(0, minimal.0);
+    }
+
+    #[proptest]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn size_range_is_respected(#[strategy(ArbStrategy::with_size_range(10, 20))] len: Len) {
+        prop_assert!((10..=20).contains(&len.0));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn size_range_produces_different_sizes() {
+        let strategy = ArbStrategy::<Len>::with_size_range(0, 1000);
+        let mut runner = TestRunner::deterministic();
+        let sizes = (0..10)
+            .map(|_| strategy.new_tree(&mut runner).unwrap().current().0)
+            .collect::<std::collections::HashSet<_>>();
+
+        assert!(sizes.len() > 1);
+    }
+
+    #[proptest]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn not_enough_data_is_retried(
+        #[strategy(ArbStrategy::with_size_range(0, 16))] value: AtLeast8,
+    ) {
+        prop_assert!(value.0 >= 8);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn too_many_rejections_fail_instead_of_hanging() {
+        let config = ArbConfig::builder().size(4).max_retries(10).build();
+        let strategy = ArbStrategy::<AtLeast8>::with_config(config);
+        let Err(reason) = strategy.new_tree(&mut TestRunner::deterministic()) else {
+            panic!("expected too many rejections");
+        };
+
+        let expected = format!("{}: too many rejections generating value", strategy.name());
+        assert_eq!(expected, reason.message());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn retry_budget_protects_runner_budget() {
+        let strategy = arb_sized::<AtLeast8>(4).with_retry_budget(5);
+        let config = ProptestConfig {
+            max_local_rejects: 10,
+            ..ProptestConfig::default()
+        };
+        let mut runner = TestRunner::new(config);
+        let expected = format!("{}: too many rejections generating value", strategy.name());
+        for _ in 0..2 {
+            let Err(reason) = strategy.new_tree(&mut runner) else {
+                panic!("expected too many rejections");
+            };
+            assert_eq!(expected, reason.message());
+        }
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn source_location_appears_in_failure_message() {
+        let config = ArbConfig::builder().size(4).max_retries(0).build();
+        let strategy = ArbStrategy::<AtLeast8>::with_config(config).with_source_location("a.rs", 7);
+        let Err(reason) = strategy.new_tree(&mut TestRunner::deterministic()) else {
+            panic!("expected too many rejections");
+        };
+
+        let name = strategy.name();
+        let expected = format!("{name} (a.rs:7): too many rejections generating value");
+        assert_eq!(expected, reason.message());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn source_location_names_regression_file() {
+        let config = ProptestConfig::default();
+        assert_eq!(
+            config.source_file,
+            arb::<u8>().regression_config(config.clone()).source_file
+        );
+
+        let strategy = arb::<u8>().with_source_location("a.rs", 7);
+        let config = arb::<u8>().regression_config(ProptestConfig::default());
+        assert_eq!(Some("a.rs"), strategy.regression_config(config).source_file);
+        assert_eq!(Some(("a.rs", 7)), strategy.source_location());
+        assert_eq!(None, arb::<u8>().source_location());
+    }
+
+    /// Records the names of all spans, the number of events, and the number of
+    /// warnings among them.
+    #[cfg(all(feature = "tracing", feature = "std"))]
+    #[derive(Default)]
+    struct Recorder {
+        spans: std::sync::Mutex<Vec<&'static str>>,
+        num_events: std::sync::Mutex<usize>,
+        num_warnings: std::sync::Mutex<usize>,
+    }
+
+    #[cfg(all(feature = "tracing", feature = "std"))]
+    impl tracing::Subscriber for &'static Recorder {
+        fn enabled(&self, _: &tracing::Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &tracing::span::Attributes<'_>) -> tracing::span::Id {
+            let mut spans = self.spans.lock().unwrap();
+            spans.push(span.metadata().name());
+            tracing::span::Id::from_u64(spans.len() as u64)
+        }
+
+        fn record(&self, _: &tracing::span::Id, _: &tracing::span::Record<'_>) {}
+
+        fn record_follows_from(&self, _: &tracing::span::Id, _: &tracing::span::Id) {}
+
+        fn event(&self, event: &tracing::Event<'_>) {
+            *self.num_events.lock().unwrap() += 1;
+            if *event.metadata().level() == tracing::Level::WARN {
+                *self.num_warnings.lock().unwrap() += 1;
+            }
+        }
+
+        fn enter(&self, _: &tracing::span::Id) {}
+
+        fn exit(&self, _: &tracing::span::Id) {}
+    }
+
+    #[cfg(all(feature = "tracing", feature = "std"))]
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn generation_and_shrinking_are_traced() {
+        let recorder: &'static Recorder = Box::leak(Box::default());
+        tracing::subscriber::with_default(recorder, || {
+            let mut tree = arb::<u8>()
+                .new_tree(&mut TestRunner::deterministic())
+                .unwrap();
+            tree.simplify();
+            tree.complicate();
+        });
+
+        let spans = recorder.spans.lock().unwrap();
+        assert_eq!(["new_tree", "simplify", "complicate"], spans.as_slice());
+        assert_eq!(3, *recorder.num_events.lock().unwrap());
+        assert_eq!(0, *recorder.num_warnings.lock().unwrap());
+    }
+
+    #[cfg(all(feature = "tracing", feature = "std"))]
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn warnings_are_traced() {
+        let recorder: &'static Recorder = Box::leak(Box::default());
+        tracing::subscriber::with_default(recorder, || {
+            warn(format_args!("a warning"));
+        });
+
+        assert_eq!(1, *recorder.num_warnings.lock().unwrap());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn size_bounds_are_exposed() {
+        let strategy = arb_sized::<u8>(16);
+        assert_eq!((16, 16), (strategy.min_size(), strategy.max_size()));
+
+        let strategy = ArbStrategy::<u8>::with_size_range(4, 32);
+        assert_eq!((4, 32), (strategy.min_size(), strategy.max_size()));
+
+        let strategy = arb_recursive::<u8>(64, 8);
+        assert_eq!((8, 64), (strategy.min_size(), strategy.max_size()));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn sizes_can_be_scaled() {
+        assert_eq!(32, arb_sized::<u8>(16).scale_size(2.0).buffer_size());
+        assert_eq!(8, arb_sized::<u8>(16).scale_size(0.5).buffer_size());
+        assert_eq!(3, arb_sized::<u8>(5).scale_size(0.5).buffer_size());
+        assert_eq!(1, arb_sized::<u8>(16).scale_size(0.001).buffer_size());
+
+        let strategy = ArbStrategy::<u8>::with_size_range(4, 32).scale_size(1.5);
+        assert_eq!((6, 48), (strategy.min_size(), strategy.max_size()));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[should_panic(expected = "size factor must be positive")]
+    fn non_positive_size_factor_panics() {
+        let _ = arb::<u8>().scale_size(0.0);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[should_panic(expected = "size factor must be positive")]
+    fn nan_size_factor_panics() {
+        let _ = arb::<u8>().scale_size(f64::NAN);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn sizes_can_be_clamped() {
+        assert_eq!(4096, arb::<[u64; 1024]>().with_max_size(4096).buffer_size());
+        assert_eq!(8, arb::<()>().with_min_size(8).buffer_size());
+        assert_eq!(
+            16,
+            arb_sized::<u8>(16)
+                .with_min_size(8)
+                .with_max_size(32)
+                .buffer_size()
+        );
+
+        let strategy = ArbStrategy::<Len>::with_size_range(0, 64)
+            .with_min_size(8)
+            .with_max_size(16);
+        let mut runner = TestRunner::deterministic();
+        for _ in 0..100 {
+            let len = strategy.new_tree(&mut runner).unwrap().current().0;
+            assert!((8..=16).contains(&len));
+        }
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn name_defaults_to_type_name() {
+        assert!(arb::<Len>().name().ends_with("Len"));
+        assert!(arb_sized::<Len>(4).name().ends_with("Len"));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn name_appears_in_failure_message() {
+        #[derive(Debug, Clone)]
+        struct Never;
+
+        impl<'a> Arbitrary<'a> for Never {
+            fn arbitrary(_: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+                Err(arbitrary::Error::IncorrectFormat)
+            }
+        }
+
+        for strategy in [arb_named::<Never>("needy"), arb_sized_named(4, "needy")] {
+            let Err(reason) = strategy.new_tree(&mut TestRunner::deterministic()) else {
+                panic!("expected too many rejections");
+            };
+
+            assert!(reason.message().contains("needy"), "{reason}");
+        }
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn size_hint_at_depth_is_used() {
+        /// Reports a size hint of exactly `depth` bytes.
+        #[derive(Debug, Clone)]
+        struct DepthSized;
+
+        impl<'a> Arbitrary<'a> for DepthSized {
+            fn arbitrary(_: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+                Ok(Self)
+            }
+
+            fn size_hint(depth: usize) -> (usize, Option<usize>) {
+                (depth, Some(depth))
+            }
+        }
+
+        let strategy = arb_size_hint_at_depth::<DepthSized>(42);
+        assert_eq!(SizeMode::Fixed(42), strategy.config.size_mode);
+        assert_eq!(SizeMode::Fixed(0), arb::<DepthSized>().config.size_mode);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn shrinking_respects_max_steps() {
+        let config = ArbConfig::builder().size(1024).shrink_max_steps(3).build();
+        let strategy = ArbStrategy::<Len>::with_config(config);
+        let tree = strategy.new_tree(&mut TestRunner::deterministic()).unwrap();
+        let (_, num_steps) = shrink(tree, |_| true);
+
+        assert_eq!(3, num_steps);
+    }
+
+    #[proptest]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn fixed_bytes_always_produce_same_value(
+        #[strategy(arb_with_bytes(vec![42, 43]).unwrap())] test: Test,
+    ) {
+        prop_assert_eq!(42, test.0);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn fixed_bytes_reproduce_failure_exactly() {
+        let bytes = vec![7; 100];
+        let strategy = arb_with_bytes::<Len>(bytes).unwrap();
+        let failing_len = |seed: u8| {
+            let config = ProptestConfig {
+                max_shrink_iters: 1000,
+                ..ProptestConfig::with_cases(1)
+            };
+            let rng = TestRng::from_seed(RngAlgorithm::ChaCha, &[seed; 32]);
+            let mut runner = TestRunner::new_with_rng(config, rng);
+            let result = runner.run(&strategy, |len| {
+                prop_assert!(len.0 < 30);
+                Ok(())
+            });
+            let Err(TestError::Fail(_, len)) = result else {
+                panic!("expected failure, got {result:?}");
+            };
+            len.0
+        };
+
+        assert_eq!(30, failing_len(0));
+        assert_eq!(30, failing_len(1));
+    }
+
+    type ArbNonZero = FilterMap<ArbStrategy<u8>, fn(u8) -> Option<NonZeroU8>>;
+
+    fn arb_non_zero() -> ArbNonZero {
+        arb::<u8>().filter_map(NonZeroU8::new)
+    }
+
+    #[proptest]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn filter_map_rejects_unmappable_values(#[strategy(arb_non_zero())] non_zero: NonZeroU8) {
+        prop_assert_ne!(0, non_zero.get());
+    }
+
+    type ArbEven = Filter<ArbStrategy<u8>, fn(&u8) -> bool>;
+
+    fn arb_even() -> ArbEven {
+        arb::<u8>().prop_assume_arb(|n| n % 2 == 0)
+    }
+
+    #[proptest]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn assumptions_reject_violating_values(#[strategy(arb_even())] even: u8) {
+        prop_assert_eq!(0, even % 2);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn shrinking_respects_assumptions() {
+        let strategy = arb::<u8>().prop_assume_arb(|n| *n >= 10);
+        let mut tree = strategy.new_tree(&mut TestRunner::deterministic()).unwrap();
+        while tree.simplify() {
+            assert!(tree.current() >= 10);
+        }
+
+        assert!(tree.current() >= 10);
+    }
+
+    #[proptest]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn tuples_of_heterogeneous_types_can_be_generated(
+        #[strategy(arb_tuple!(Test))] _single: (Test,),
+        #[strategy(arb_tuple!(Test, Rgb, Len,))] _triple: (Test, Rgb, Len),
+        #[strategy(arb_tuple!(u8, u16, u32, u64, i8, i16, i32, i64, bool, char, (), Test))]
+        _twelve: (u8, u16, u32, u64, i8, i16, i32, i64, bool, char, (), Test),
+    ) {
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn tuple_elements_are_sized_independently() {
+        let (byte, color) = arb_tuple!(u8, Rgb);
+        assert_eq!(arb::<u8>(), byte);
+        assert_eq!(arb::<Rgb>(), color);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn recursive_types_are_shallow_and_deep() {
+        /// As deep as the number of bytes it was generated from.
+        #[derive(Debug, Clone)]
+        enum Nested {
+            Leaf,
+            Node(Box<Nested>),
+        }
+
+        impl Nested {
+            fn depth(&self) -> usize {
+                match self {
+                    Nested::Leaf => 0,
+                    Nested::Node(inner) => 1 + inner.depth(),
+                }
+            }
+        }
+
+        impl<'a> Arbitrary<'a> for Nested {
+            fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+                if u.is_empty() {
+                    return Ok(Self::Leaf);
+                }
+                u8::arbitrary(u)?;
+
+                Ok(Self::Node(Box::new(Self::arbitrary(u)?)))
+            }
+        }
+
+        let strategy = arb_recursive::<Nested>(4, 200);
+        let depths = strategy
+            .into_iter_with_runner(TestRunner::deterministic())
+            .take(100)
+            .map(|nested| nested.depth())
+            .collect::<Vec<_>>();
+
+        assert!(depths.iter().all(|&depth| depth == 4 || depth == 200));
+        assert!(depths.contains(&4));
+        assert!(depths.contains(&200));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn recursion_probability_depends_on_size_hint() {
+        let probability =
+            |base_size| match arb_recursive::<AtLeast8>(base_size, 100).config.size_mode {
+                SizeMode::Bimodal {
+                    recursive_per_mille,
+                    ..
+                } => recursive_per_mille,
+                size_mode => panic!("unexpected size mode {size_mode:?}"),
+            };
+
+        assert_eq!(750, probability(4));
+        assert_eq!(500, probability(16));
+        assert_eq!(250, probability(100));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn huge_size_hint_does_not_overflow_recursion_probability() {
+        /// Claims to need almost all of memory.
+        #[derive(Debug, Clone)]
+        struct Huge;
+
+        impl<'a> Arbitrary<'a> for Huge {
+            fn arbitrary(_: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+                Ok(Self)
+            }
+
+            fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+                (usize::MAX / 2, None)
+            }
+        }
+
+        let strategy = arb_recursive::<Huge>(4, 100);
+        let SizeMode::Bimodal {
+            recursive_per_mille,
+            ..
+        } = strategy.config.size_mode
+        else {
+            panic!("unexpected size mode {:?}", strategy.config.size_mode);
+        };
+        assert_eq!(750, recursive_per_mille);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn recursive_strategy_sizes_form_trees() {
+        let strategy = arb_recursive_strategy::<Len>(1, 10, 2, 2);
+        let mut runner = TestRunner::deterministic();
+        let sizes = (0..1000)
+            .map(|_| strategy.new_tree(&mut runner).unwrap().current().0)
+            .collect::<std::collections::HashSet<_>>();
+
+        // Leaves, nodes with one or two leaves, and so on up to two nodes with
+        // two leaves each.
+        assert!(sizes.contains(&1));
+        assert!(sizes.contains(&11));
+        assert!(sizes.contains(&12));
+        assert!(
+            sizes
+                .iter()
+                .all(|&size| size == 1 || (11..=34).contains(&size))
+        );
+        assert!(sizes.iter().any(|&size| size > 12));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn recursive_strategy_without_depth_uses_base_size() {
+        let strategy = arb_recursive_strategy::<Len>(3, 10, 0, 2);
+        let mut runner = TestRunner::deterministic();
+        for _ in 0..100 {
+            assert_eq!(3, strategy.new_tree(&mut runner).unwrap().current().0);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn strategies_display_type_and_size() {
+        assert_eq!(
+            "ArbStrategy<u8>(size=256)",
+            arb_sized::<u8>(256).to_string()
+        );
+        assert_eq!(
+            "ArbStrategy<u8>(size=4..=8)",
+            ArbStrategy::<u8>::with_size_range(4, 8).to_string()
+        );
+        assert_eq!(
+            "ArbStrategy<u8>(size=4|8)",
+            arb_recursive::<u8>(4, 8).to_string()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn value_trees_display_size_and_value() {
+        let mut tree = ArbValueTree::<Test>::from_bytes(vec![7, 0]).unwrap();
+        assert_eq!(
+            "ArbValueTree { size: 2/2, value: Test(7) }",
+            tree.to_string()
+        );
+
+        tree.simplify();
+        assert_eq!(
+            "ArbValueTree { size: 2/2, value: Test(0) }",
+            tree.to_string()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn consumed_bytes_are_reported_on_construction() {
+        let (tree, consumed) = ArbValueTree::<(u8, u16)>::new_with_stats(vec![1; 8]).unwrap();
+        assert_eq!(3, consumed);
+        assert_eq!(tree.bytes_consumed(), consumed);
+        assert_eq!(8, tree.current_size());
+
+        let (_, consumed) = ArbValueTree::<Len>::new_with_stats(vec![1; 8]).unwrap();
+        assert_eq!(0, consumed);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn trees_can_be_built_from_remaining_unstructured_bytes() {
+        let bytes = [1, 2, 3, 4, 5];
+        let mut u = arbitrary::Unstructured::new(&bytes);
+        u.bytes(1).unwrap();
+
+        let tree = ArbValueTree::<u32>::try_from(u).unwrap();
+        assert_eq!([2, 3, 4, 5], tree.raw_bytes());
+        assert_eq!(u32::from_le_bytes([2, 3, 4, 5]), tree.current());
+
+        let u = arbitrary::Unstructured::new(&[0; 4]);
+        let result = ArbValueTree::<AtLeast8>::try_from(u);
+        assert!(matches!(result, Err(arbitrary::Error::NotEnoughData)));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn clones_shrink_independently() {
+        let tree = ArbValueTree::<u32>::new(vec![1, 2, 3, 4]).unwrap();
+        let mut branch_a = tree.clone();
+        let mut branch_b = tree.clone();
+
+        while branch_a.simplify() {}
+        assert_eq!(0, branch_a.current());
+        assert_eq!(tree.current(), branch_b.current());
+        assert_eq!(tree.raw_bytes(), branch_b.raw_bytes());
+
+        // Rejecting every simplification keeps the original value.
+        while branch_b.simplify() {
+            assert!(branch_b.complicate());
+        }
+        assert_eq!(tree.current(), branch_b.current());
+        assert_eq!(0, branch_a.current());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn bytes_are_shown_in_hex() {
+        let mut tree = ArbValueTree::<Len>::new(vec![0xde, 0xad, 0xbe, 0xef, 1]).unwrap();
+        assert_eq!("deadbeef01", tree.bytes_as_hex());
+
+        while tree.simplify() {}
+        assert_eq!("", tree.bytes_as_hex());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn verbose_strategies_show_bytes_in_debug_output() {
+        let mut runner = TestRunner::deterministic();
+        let tree = arb_sized::<u8>(2)
+            .with_verbose_bytes()
+            .new_tree(&mut runner)
+            .unwrap();
+        let hex = tree.bytes_as_hex();
+        assert!(format!("{tree:?}").contains(&format!("bytes_as_hex: \"{hex}\"")));
+
+        let tree = arb_sized::<u8>(2).new_tree(&mut runner).unwrap();
+        let is_verbose = format!("{tree:?}").contains("bytes_as_hex");
+        assert_eq!(cfg!(feature = "verbose-bytes"), is_verbose);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn trees_are_equal_if_generated_from_same_bytes() {
+        let tree = ArbValueTree::<Rgb>::new(vec![1, 2, 3]).unwrap();
+        let mut other = ArbValueTree::<Rgb>::new(vec![1, 2, 3]).unwrap();
+        assert_eq!(tree, other);
+
+        assert!(other.simplify());
+        assert_ne!(tree, other);
+        assert!(other.complicate());
+        assert_eq!(tree, other);
+
+        assert_ne!(tree, ArbValueTree::<Rgb>::new(vec![1, 2, 3, 0]).unwrap());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn equal_trees_are_deduplicated() {
+        let corpus = [vec![1, 2, 3], vec![4, 5, 6], vec![1, 2, 3]]
+            .into_iter()
+            .map(|bytes| ArbValueTree::<Rgb>::new(bytes).unwrap())
+            .collect::<std::collections::HashSet<_>>();
+
+        assert_eq!(2, corpus.len());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn vecs_have_the_requested_length() {
+        let mut runner = TestRunner::deterministic();
+        for length in [0, 1, 10] {
+            let colors = arb_vec_of_size::<Rgb>(length)
+                .new_tree(&mut runner)
+                .unwrap()
+                .current();
+            assert_eq!(length, colors.len());
+        }
+
+        let colors = arb_vec::<Rgb>(3_usize..6)
+            .new_tree(&mut runner)
+            .unwrap()
+            .current();
+        assert!((3..6).contains(&colors.len()));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn vec_elements_shrink() {
+        let config = ProptestConfig {
+            max_shrink_iters: 10_000,
+            ..ProptestConfig::default()
+        };
+        let mut runner = TestRunner::new_with_rng(config, TestRunner::deterministic().new_rng());
+        let result = runner.run(&arb_vec_of_size::<Rgb>(10), |colors| {
+            prop_assert!(colors.iter().all(|color| color.r <= 100));
+            Ok(())
+        });
+        let Err(TestError::Fail(_, colors)) = result else {
+            panic!("expected failure, got {result:?}");
+        };
+
+        let black = Rgb { r: 0, g: 0, b: 0 };
+        let (reddish, others): (Vec<Rgb>, Vec<Rgb>) =
+            colors.iter().partition(|color| color.r > 100);
+        assert_eq!(10, colors.len());
+        assert_eq!(1, reddish.len());
+        assert_eq!((0, 0), (reddish[0].g, reddish[0].b));
+        assert!(others.iter().all(|&color| color == black));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn repeated_values_are_independent() {
+        let values = arb_sized::<u64>(8)
+            .repeat(10)
+            .new_tree(&mut TestRunner::deterministic())
+            .unwrap()
+            .current();
+
+        assert_eq!(10, values.len());
+        assert!(values.windows(2).all(|w| w[0] != w[1]));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn repeated_values_shrink_independently() {
+        let mut tree = arb_sized::<u64>(8)
+            .repeat(3)
+            .new_tree(&mut TestRunner::deterministic())
+            .unwrap();
+        let values = tree.current();
+
+        assert!(tree.simplify());
+        let simpler = tree.current();
+        assert_eq!(3, simpler.len());
+        assert!(simpler[0] < values[0]);
+        assert_eq!(values[1..], simpler[1..]);
+
+        while tree.simplify() {}
+        assert_eq!(vec![0; 3], tree.current());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn dependent_strategies_vary_buffer_size() {
+        let strategy = arb::<u8>().and_then(|n| arb_sized::<Len>(usize::from(n)));
+        let mut runner = TestRunner::deterministic();
+        let lens = (0..100)
+            .map(|_| strategy.new_tree(&mut runner).unwrap().current().0)
+            .collect::<Vec<_>>();
+
+        assert!(lens.iter().all(|&len| len <= usize::from(u8::MAX)));
+        assert!(lens.windows(2).any(|w| w[0] != w[1]));
+
+        let mut runner = TestRunner::deterministic();
+        for _ in 0..10 {
+            let len = arb_and_then(|n: u8| arb_sized::<Len>(usize::from(n % 8)))
+                .new_tree(&mut runner)
+                .unwrap()
+                .current();
+            assert!(len.0 < 8);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn dependent_strategies_have_nameable_types() {
+        type Dependent = Flatten<Map<ArbStrategy<u8>, fn(u8) -> ArbStrategy<Len>>>;
+        struct Holder {
+            strategy: Dependent,
+        }
+
+        let holder = Holder {
+            strategy: arb::<u8>().prop_flat_map_arb(|n| arb_sized(usize::from(n % 8))),
+        };
+        let mut runner = TestRunner::deterministic();
+        for _ in 0..10 {
+            let len = holder.strategy.new_tree(&mut runner).unwrap().current();
+            assert!(len.0 < 8);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn perturbed_values_are_correlated() {
+        let strategy = arb::<u32>().prop_perturb_arb(|a, rng| a ^ (rng.next_u32() & 1));
+        let mut runner = TestRunner::deterministic();
+        let mut num_perturbed = 0;
+        for _ in 0..100 {
+            let mut tree = strategy.new_tree(&mut runner).unwrap();
+            let (a, b) = tree.current();
+            assert_eq!(a | 1, b | 1);
+            num_perturbed += usize::from(a != b);
+
+            while tree.simplify() {
+                let (a, b) = tree.current();
+                assert_eq!(a | 1, b | 1);
+            }
+        }
+        assert!(0 < num_perturbed && num_perturbed < 100);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn case_limit_is_advisory() {
+        let strategy = arb::<u8>().cases(10);
+        assert_eq!(Some(10), strategy.max_cases());
+        assert_eq!(None, arb::<u8>().max_cases());
+
+        let mut runner = TestRunner::deterministic();
+        let values = strategy
+            .clone()
+            .into_iter_with_runner(runner.clone())
+            .take(20)
+            .count();
+        assert_eq!(20, values);
+
+        let config = ProptestConfig::with_cases(strategy.max_cases().unwrap());
+        runner = TestRunner::new_with_rng(config, runner.new_rng());
+        let num_cases = core::cell::Cell::new(0);
+        runner
+            .run(&strategy, |_| {
+                num_cases.set(num_cases.get() + 1);
+                Ok(())
+            })
+            .unwrap();
+        assert_eq!(10, num_cases.get());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn just_generates_constant_value() {
+        /// Does not implement [`Arbitrary`].
+        #[derive(Debug, Clone, PartialEq)]
+        struct Opaque(&'static str);
+
+        let mut runner = TestRunner::deterministic();
+        let strategy = (arb_just(Opaque("answer")), arb::<u8>());
+        for _ in 0..10 {
+            let mut tree = strategy.new_tree(&mut runner).unwrap();
+            while tree.simplify() {}
+            assert_eq!(Opaque("answer"), tree.current().0);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn probing_accounts_for_peeked_bytes() {
+        /// Inspects 1000 bytes without consuming them.
+        #[derive(Debug, Clone)]
+        struct Peeking;
+
+        impl<'a> Arbitrary<'a> for Peeking {
+            fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+                u.peek_bytes(1000).ok_or(arbitrary::Error::NotEnoughData)?;
+                Ok(Self)
+            }
+        }
+
+        assert_eq!(256, arb::<Peeking>().buffer_size());
+        assert_eq!(2048, arb_probed::<Peeking>().buffer_size());
+        assert!(
+            arb_probed::<Peeking>()
+                .new_tree(&mut TestRunner::deterministic())
+                .is_ok()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn probing_never_shrinks_buffer() {
+        assert_eq!(arb::<Vec<u8>>(), arb_probed::<Vec<u8>>());
+        assert_eq!(arb::<u32>(), arb_probed::<u32>());
+        assert_eq!(256, arb_probed::<AtLeast8>().buffer_size());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn computed_sizes_can_be_inspected() {
+        assert_eq!((3, Some(3)), size_hint_bytes::<Rgb>());
+        assert_eq!(3, arb::<Rgb>().buffer_size());
+
+        assert_eq!((8, None), size_hint_bytes::<AtLeast8>());
+        assert_eq!(256, arb::<AtLeast8>().buffer_size());
+
+        assert_eq!(
+            20,
+            ArbStrategy::<Rgb>::with_size_range(10, 20).buffer_size()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn minimal_value_is_generated_from_zero_bytes() {
+        let mut tree = ArbStrategy::<Rgb>::minimal().unwrap();
+        assert_eq!(Rgb { r: 0, g: 0, b: 0 }, tree.current());
+        assert!(!tree.simplify());
+        assert!(!tree.complicate());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn minimal_value_respects_size_hint() {
+        assert_eq!(8, ArbStrategy::<AtLeast8>::minimal().unwrap().current().0);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn single_value_can_be_generated() {
+        let Test(_t) = arb_one().unwrap();
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn seeded_generation_is_deterministic() {
+        let colors = (0..10).map(arb_one_seeded::<Rgb>);
+        let same_colors = (0..10).map(arb_one_seeded::<Rgb>);
+        assert!(
+            colors
+                .map(Result::unwrap)
+                .eq(same_colors.map(Result::unwrap))
+        );
+
+        let distinct_colors = (0..10)
+            .map(|seed| arb_one_seeded::<Rgb>(seed).unwrap())
+            .map(|Rgb { r, g, b }| (r, g, b))
+            .collect::<std::collections::HashSet<_>>();
+        assert!(distinct_colors.len() > 1);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn shrinking_takes_logarithmically_many_steps() {
+        let tree = ArbValueTree::<Len>::new(vec![0; 1024]).unwrap();
+        let (minimal, num_steps) = shrink(tree, |&Len(len)| len >= 300);
+
+        assert_eq!(300, minimal.0);
+        assert!(
+            num_steps <= 2 * 1024_usize.ilog2() as usize,
+            "{num_steps} steps"
+        );
+    }
+
+    #[proptest]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn rgb_shrinks_to_black(#[any(proptest::collection::size_range(0..64).lift())] bytes: Vec<u8>) {
+        let tree = ArbValueTree::<Rgb>::from_bytes(bytes).unwrap();
+        let minimal = tree.shrink_path().last().copied().unwrap_or(tree.current());
+
+        prop_assert_eq!(Rgb { r: 0, g: 0, b: 0 }, minimal);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn bytes_of_highest_value_are_zeroed_first() {
+        let mut tree = ArbValueTree::<[u8; 4]>::new(vec![3, 0x80, 1, 0x80]).unwrap();
+        let mut zeroed_indices = vec![];
+        while tree.simplify() && tree.current_size() == 4 {
+            let (index, _) = tree.zeroed.unwrap();
+            zeroed_indices.push(index);
+        }
+
+        assert_eq!(vec![3, 1, 0, 2], zeroed_indices);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn bytes_are_zeroed_before_truncation() {
+        #[derive(Debug, Clone, Arbitrary)]
+        struct Embedded {
+            _prefix: u8,
+            value: u32,
+        }
+
+        let mut tree = ArbValueTree::<Embedded>::new(vec![0xff; 8]).unwrap();
+        assert_ne!(0, tree.current().value);
+        while tree.current().value != 0 {
+            assert!(tree.simplify());
+        }
+
+        assert_eq!(8, tree.current_size());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn complicating_restores_zeroed_byte() {
+        let mut tree = ArbValueTree::<Test>::new(vec![42]).unwrap();
+        assert!(tree.simplify());
+        assert_eq!(0, tree.current().0);
+        assert_eq!([0], tree.raw_bytes());
+
+        assert!(tree.complicate());
+        assert_eq!(42, tree.current().0);
+        assert_eq!([42], tree.raw_bytes());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn injected_bytes_replace_current_value() {
+        let mut tree = ArbValueTree::<Rgb>::new(vec![1, 2, 3, 4, 5, 6]).unwrap();
+        tree.inject_bytes(&[7, 8]).unwrap();
+
+        assert_eq!(Rgb { r: 7, g: 8, b: 0 }, tree.current());
+        assert_eq!(2, tree.current_size());
+        assert_eq!([7, 8, 3, 4, 5, 6], tree.raw_bytes());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn injection_can_be_undone() {
+        let mut tree = ArbValueTree::<Rgb>::new(vec![1, 2, 3, 4]).unwrap();
+        tree.inject_bytes(&[9; 6]).unwrap();
+        assert_eq!(Rgb { r: 9, g: 9, b: 9 }, tree.current());
+
+        assert!(tree.complicate());
+        assert_eq!(Rgb { r: 1, g: 2, b: 3 }, tree.current());
+        assert_eq!([1, 2, 3, 4], tree.raw_bytes());
+        assert!(!tree.complicate());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn shrinking_continues_from_injected_bytes() {
+        let mut tree = ArbValueTree::<Rgb>::new(vec![1, 2, 3]).unwrap();
+        tree.inject_bytes(&[0, 0, 5]).unwrap();
+        assert!(tree.simplify());
+        assert_eq!(Rgb { r: 0, g: 0, b: 0 }, tree.current());
+
+        assert!(tree.complicate());
+        assert_eq!(Rgb { r: 0, g: 0, b: 5 }, tree.current());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn rejected_injection_leaves_tree_unchanged() {
+        let mut tree = ArbValueTree::<AtLeast8>::new(vec![1; 8]).unwrap();
+        let before = format!("{tree:?}");
+
+        assert!(tree.inject_bytes(&[2; 4]).is_err());
+        assert_eq!(before, format!("{tree:?}"));
+    }
+
+    // Not on `wasm32`, which has no file system.
+    #[cfg(feature = "serde")]
+    #[test]
+    fn tree_can_be_saved_and_loaded() {
+        let mut tree = ArbValueTree::<Rgb>::new(vec![1, 2, 3, 4, 5, 6]).unwrap();
+        assert!(tree.simplify());
+        assert!(tree.complicate());
+        assert!(tree.simplify());
+
+        let path = std::env::temp_dir().join(format!("tree-{}.json", std::process::id()));
+        tree.save_to_file(&path).unwrap();
+        let loaded = ArbValueTree::<Rgb>::load_from_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(tree.current(), loaded.current());
+        assert_eq!(tree.raw_bytes(), loaded.raw_bytes());
+        assert_eq!(tree.current_size(), loaded.current_size());
+        assert_eq!(tree.shrink_path(), loaded.shrink_path());
+    }
+
+    #[proptest]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn complicating_restores_exact_bytes(
+        #[any(proptest::collection::size_range(0..64).lift())] bytes: Vec<u8>,
+    ) {
+        let mut tree = ArbValueTree::<Rgb>::new(bytes.clone()).unwrap();
+        let original = tree.current();
+        prop_assume!(tree.simplify());
+        let rejected = tree.raw_bytes()[..tree.current_size()].to_vec();
+
+        prop_assert!(tree.complicate());
+        prop_assert_eq!(original, tree.current());
+        prop_assert_eq!(&bytes, tree.raw_bytes());
+        prop_assert_eq!(bytes.len(), tree.current_size());
+
+        if tree.simplify() {
+            prop_assert_ne!(rejected, &tree.raw_bytes()[..tree.current_size()]);
+        }
+    }
+
+    #[proptest]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn complicating_does_not_revisit_rejected_prefixes(#[strategy(2_usize..1024)] len: usize) {
+        let mut tree = ArbValueTree::<Len>::new(vec![0; len]).unwrap();
+        prop_assert!(tree.simplify());
+        let rejected_size = tree.current_size();
+
+        prop_assert!(tree.complicate());
+        if tree.simplify() {
+            prop_assert!(tree.current_size() > rejected_size);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn shrinking_to_empty_prefix_is_possible() {
+        let tree = ArbValueTree::<Len>::new(vec![0; 1024]).unwrap();
+        let (minimal, _) = shrink(tree, |_| true);
+
+        assert_eq!(0, minimal.0);
     }
 }
@@ -0,0 +1,104 @@
+use alloc::vec::Vec;
+use core::fmt::Debug;
+use core::fmt::Formatter;
+
+use proptest::prelude::RngCore;
+use proptest::strategy::Strategy;
+use proptest::test_runner::TestRunner;
+
+use crate::ArbInterop;
+use crate::ArbStrategy;
+use crate::ArbValueTree;
+
+/// A [`proptest::strategy::Strategy`] like [`ArbStrategy`] that transforms the
+/// random data before feeding it to the [`arbitrary::Arbitrary`]
+/// implementation. See [`ArbStrategy::map_bytes`].
+///
+/// Unlike [`BiasedArbStrategy`](crate::BiasedArbStrategy), the transformation
+/// applies to every test case. Shrinking operates on the transformed random
+/// data without applying the transformation again, so shrunk values might not
+/// satisfy the constraints it establishes.
+#[derive(Clone)]
+pub struct MapBytesArbStrategy<A: ArbInterop, F> {
+    strategy: ArbStrategy<A>,
+    f: F,
+}
+
+impl<A: ArbInterop> ArbStrategy<A> {
+    /// Turns this strategy into a [`MapBytesArbStrategy`], which calls `f` on
+    /// the random data of each test case, for example to steer generation
+    /// towards a specific code path without changing the
+    /// [`arbitrary::Arbitrary`] implementation.
+    ///
+    /// ```
+    /// # use proptest_arbitrary_adapter::arb;
+    /// let strategy = arb::<u32>().map_bytes(|bytes| bytes[0] = 0xff);
+    /// ```
+    ///
+    /// The random data has the size this strategy samples, which may be zero.
+    /// `f` may also resize it.
+    pub fn map_bytes<F: Fn(&mut Vec<u8>)>(self, f: F) -> MapBytesArbStrategy<A, F> {
+        MapBytesArbStrategy { strategy: self, f }
+    }
+}
+
+impl<A: ArbInterop, F> Debug for MapBytesArbStrategy<A, F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("MapBytesArbStrategy")
+            .field("strategy", &self.strategy)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<A: ArbInterop, F: Fn(&mut Vec<u8>)> Strategy for MapBytesArbStrategy<A, F> {
+    type Tree = ArbValueTree<A>;
+    type Value = A;
+
+    fn new_tree(&self, run: &mut TestRunner) -> proptest::strategy::NewTree<Self> {
+        self.strategy.new_tree_with(run, |run, bytes| {
+            run.rng().fill_bytes(bytes);
+            (self.f)(bytes);
+        })
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, feature(coverage_attribute))]
+mod tests {
+    use proptest::strategy::ValueTree;
+
+    use super::*;
+    use crate::arb_sized;
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn random_data_is_transformed() {
+        let strategy = arb_sized::<u32>(4).map_bytes(|bytes| bytes[0] = 0xff);
+        let mut runner = TestRunner::deterministic();
+        for _ in 0..100 {
+            let tree = strategy.new_tree(&mut runner).unwrap();
+            assert_eq!(0xff, tree.raw_bytes()[0]);
+            assert_eq!(0xff, tree.current() & 0xff);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn random_data_may_be_resized() {
+        let strategy = arb_sized::<u32>(4).map_bytes(|bytes| bytes.truncate(1));
+        let tree = strategy.new_tree(&mut TestRunner::deterministic()).unwrap();
+
+        assert_eq!(1, tree.raw_bytes().len());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn shrinking_operates_on_transformed_data() {
+        let strategy = arb_sized::<u8>(1).map_bytes(|bytes| bytes[0] = 0xff);
+        let mut tree = strategy.new_tree(&mut TestRunner::deterministic()).unwrap();
+        assert_eq!(0xff, tree.current());
+
+        while tree.simplify() {}
+        assert_eq!(0, tree.current());
+    }
+}
@@ -0,0 +1,151 @@
+use arbitrary::Arbitrary;
+use arbitrary::MaxRecursionReached;
+use arbitrary::Unstructured;
+use arbitrary::size_hint;
+
+use crate::ArbInterop;
+use crate::ArbStrategy;
+use crate::ArbValueTree;
+use crate::arb_sized;
+use crate::config;
+
+/// The largest size of random data to generate `A` from, given its
+/// [`size_hint`](Arbitrary::size_hint).
+fn max_size((low, opt_high): (usize, Option<usize>)) -> usize {
+    opt_high
+        .unwrap_or((2 * low).max(config::DEFAULT_SIZE))
+        .max(low)
+}
+
+/// Generates strategies of a random fixed size, for meta-fuzzing: checking
+/// that an [`arbitrary::Arbitrary`] implementation copes with all the sizes of
+/// random data it might be fed.
+///
+/// The size lies within the bounds of `A`'s
+/// [`size_hint`](arbitrary::Arbitrary::size_hint). Without an upper bound, the
+/// largest size is the one [`arb`](crate::arb) would use.
+///
+/// ```
+/// # use proptest_arbitrary_adapter::ArbStrategy;
+/// # use proptest_arbitrary_adapter::arb;
+/// # use proptest::strategy::Strategy;
+/// # use test_strategy::proptest;
+/// #[proptest]
+/// fn no_size_fails(#[strategy(arb())] strategy: ArbStrategy<(u8, char)>) {
+///     strategy.new_tree(&mut Default::default()).unwrap();
+/// }
+/// ```
+impl<'a, A: ArbInterop> Arbitrary<'a> for ArbStrategy<A> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let (low, opt_high) = A::size_hint(0);
+        let size = u.int_in_range(low..=max_size((low, opt_high)))?;
+
+        Ok(arb_sized(size))
+    }
+
+    fn size_hint(_: usize) -> (usize, Option<usize>) {
+        (0, Some(size_of::<usize>()))
+    }
+}
+
+/// Generates trees from random data of a random size, for testing shrinking:
+/// for example, checking that a [`ShrinkStrategy`](crate::ShrinkStrategy)
+/// always terminates.
+///
+/// The size is chosen like for [`ArbStrategy`]s, but limited to the remaining
+/// data. Random data the [`arbitrary::Arbitrary`] implementation of `A`
+/// rejects yields [`arbitrary::Error::IncorrectFormat`].
+///
+/// ```
+/// # use proptest_arbitrary_adapter::ArbValueTree;
+/// # use proptest_arbitrary_adapter::arb;
+/// # use proptest::strategy::ValueTree;
+/// # use test_strategy::proptest;
+/// #[proptest]
+/// fn shrinking_terminates(#[strategy(arb())] mut tree: ArbValueTree<Vec<u8>>) {
+///     while tree.simplify() {}
+/// }
+/// ```
+impl<'a, A: ArbInterop> Arbitrary<'a> for ArbValueTree<A> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let (low, opt_high) = A::try_size_hint(0).unwrap_or_default();
+        let size = u.int_in_range(low..=max_size((low, opt_high)))?;
+        let bytes = u.bytes(size.min(u.len()))?.to_vec();
+
+        ArbValueTree::new(bytes).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        Self::try_size_hint(depth).unwrap_or_default()
+    }
+
+    fn try_size_hint(depth: usize) -> Result<(usize, Option<usize>), MaxRecursionReached> {
+        size_hint::try_recursion_guard(depth, |depth| {
+            let size = max_size(A::try_size_hint(depth)?);
+            Ok((0, Some(size_of::<usize>() + size)))
+        })
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, feature(coverage_attribute))]
+mod tests {
+    use proptest::prop_assert;
+    use proptest::prop_assert_eq;
+    use proptest::strategy::Strategy;
+    use proptest::strategy::ValueTree;
+    use proptest::test_runner::TestRunner;
+    use test_strategy::proptest;
+
+    use super::*;
+    use crate::arb;
+    use crate::config::SizeMode;
+
+    fn size<A: ArbInterop>(strategy: ArbStrategy<A>) -> usize {
+        let SizeMode::Fixed(size) = strategy.config.size_mode else {
+            panic!("expected a fixed size, got {:?}", strategy.config.size_mode);
+        };
+        size
+    }
+
+    #[proptest]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn sizes_respect_bounded_size_hints(#[strategy(arb())] strategy: ArbStrategy<(u8, u32)>) {
+        prop_assert_eq!(5, size(strategy));
+    }
+
+    #[proptest]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn sizes_respect_unbounded_size_hints(#[strategy(arb())] strategy: ArbStrategy<Vec<u8>>) {
+        prop_assert!(size(strategy) <= config::DEFAULT_SIZE);
+    }
+
+    #[proptest]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn generated_strategies_generate(#[strategy(arb())] strategy: ArbStrategy<Option<u16>>) {
+        prop_assert!(strategy.new_tree(&mut TestRunner::deterministic()).is_ok());
+    }
+
+    #[proptest]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn generated_trees_shrink_to_zero(#[strategy(arb())] mut tree: ArbValueTree<u32>) {
+        while tree.simplify() {}
+        prop_assert_eq!(0, tree.current());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn rejected_random_data_is_an_incorrect_format() {
+        #[derive(Debug, Clone)]
+        struct Never;
+
+        impl<'a> Arbitrary<'a> for Never {
+            fn arbitrary(_: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+                Err(arbitrary::Error::NotEnoughData)
+            }
+        }
+
+        let result = ArbValueTree::<Never>::arbitrary(&mut Unstructured::new(&[0; 16]));
+        assert!(matches!(result, Err(arbitrary::Error::IncorrectFormat)));
+    }
+}
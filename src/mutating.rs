@@ -0,0 +1,218 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use proptest::prelude::RngCore;
+use proptest::strategy::Strategy;
+use proptest::strategy::ValueTree;
+use proptest::test_runner::TestRunner;
+
+use crate::ArbInterop;
+use crate::ArbStrategy;
+use crate::ArbValueTree;
+use crate::arb;
+use crate::assert_probability;
+use crate::lock;
+use crate::random_below;
+
+/// A [`proptest::strategy::Strategy`] that generates new test cases by
+/// mutating the random data of previous ones, like coverage-guided fuzzers do.
+///
+/// The strategy keeps a pool of the random data that successfully generated
+/// values. For every test case, with some probability, the random data is a
+/// mutation of a pool entry instead of fresh random data. Mutations are bit
+/// flips, byte swaps, byte insertions, and byte deletions. See
+/// [`arb_mutating`].
+///
+/// Clones of the strategy share the pool.
+#[derive(Clone, Debug)]
+pub struct MutatingArbStrategy<A: ArbInterop> {
+    strategy: ArbStrategy<A>,
+    pool_size: usize,
+    mutation_probability: f64,
+    pool: Arc<Mutex<VecDeque<Vec<u8>>>>,
+}
+
+impl<A: ArbInterop> MutatingArbStrategy<A> {
+    /// Mutates the random data of the given `strategy`. The pool holds at most
+    /// `pool_size` entries, the oldest of which is evicted first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mutation_probability` is not in the range `[0.0, 1.0]`.
+    pub fn new(strategy: ArbStrategy<A>, pool_size: usize, mutation_probability: f64) -> Self {
+        assert_probability(mutation_probability);
+
+        Self {
+            strategy,
+            pool_size,
+            mutation_probability,
+            pool: Arc::default(),
+        }
+    }
+
+    /// Picks a random pool entry to mutate, if mutation is due.
+    fn pick_from_pool(&self, run: &mut TestRunner) -> Option<Vec<u8>> {
+        let pool = lock(&self.pool);
+        if pool.is_empty() {
+            return None;
+        }
+
+        let is_mutation_due = proptest::bool::weighted(self.mutation_probability)
+            .new_tree(run)
+            .ok()?
+            .current();
+        if !is_mutation_due {
+            return None;
+        }
+
+        let index = random_below(run, pool.len());
+        Some(pool[index].clone())
+    }
+
+    /// Fills the given buffer with either a mutated pool entry or fresh random
+    /// data.
+    fn fill_bytes(&self, run: &mut TestRunner, bytes: &mut Vec<u8>) {
+        match self.pick_from_pool(run) {
+            Some(pool_entry) => {
+                *bytes = pool_entry;
+                mutate(run, bytes);
+            }
+            None => run.rng().fill_bytes(bytes),
+        }
+    }
+
+    fn add_to_pool(&self, bytes: &[u8]) {
+        if self.pool_size == 0 {
+            return;
+        }
+
+        let mut pool = lock(&self.pool);
+        if pool.len() >= self.pool_size {
+            pool.pop_front();
+        }
+        pool.push_back(bytes.to_vec());
+    }
+}
+
+/// Applies one random mutation to the given bytes.
+fn mutate(run: &mut TestRunner, bytes: &mut Vec<u8>) {
+    const NUM_MUTATIONS: usize = 4;
+
+    // The only possible mutation of no bytes is an insertion.
+    let mutation = if bytes.is_empty() {
+        2
+    } else {
+        random_below(run, NUM_MUTATIONS)
+    };
+
+    match mutation {
+        0 => {
+            let index = random_below(run, bytes.len());
+            bytes[index] ^= 1 << random_below(run, 8);
+        }
+        1 => {
+            let index = random_below(run, bytes.len());
+            let other_index = random_below(run, bytes.len());
+            bytes.swap(index, other_index);
+        }
+        2 => {
+            let index = random_below(run, bytes.len() + 1);
+            bytes.insert(index, run.rng().next_u32() as u8);
+        }
+        _ => {
+            let index = random_below(run, bytes.len());
+            bytes.remove(index);
+        }
+    }
+}
+
+impl<A: ArbInterop> Strategy for MutatingArbStrategy<A> {
+    type Tree = ArbValueTree<A>;
+    type Value = A;
+
+    fn new_tree(&self, run: &mut TestRunner) -> proptest::strategy::NewTree<Self> {
+        let tree = self
+            .strategy
+            .new_tree_with(run, |run, bytes| self.fill_bytes(run, bytes))?;
+        self.add_to_pool(tree.raw_bytes());
+
+        Ok(tree)
+    }
+}
+
+/// Constructs a [`MutatingArbStrategy`] for a given [`arbitrary::Arbitrary`]
+/// type, sized like [`arb`] does.
+///
+/// The pool holds at most `pool_size` entries. For every test case, random data
+/// from the pool is mutated with probability `mutation_probability`.
+///
+/// # Panics
+///
+/// Panics if `mutation_probability` is not in the range `[0.0, 1.0]`.
+pub fn arb_mutating<A: ArbInterop>(
+    pool_size: usize,
+    mutation_probability: f64,
+) -> MutatingArbStrategy<A> {
+    MutatingArbStrategy::new(arb(), pool_size, mutation_probability)
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, feature(coverage_attribute))]
+mod tests {
+    use super::*;
+    use crate::test_util::Len;
+
+    fn generate_lens(strategy: &MutatingArbStrategy<Len>) -> Vec<usize> {
+        let mut runner = TestRunner::deterministic();
+        (0..100)
+            .map(|_| strategy.new_tree(&mut runner).unwrap().current().0)
+            .collect()
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn mutations_change_previous_bytes_slightly() {
+        let strategy = arb_mutating::<Len>(1, 1.0);
+        let lens = generate_lens(&strategy);
+
+        assert_eq!(256, lens[0]);
+        assert!(lens.windows(2).all(|w| w[0].abs_diff(w[1]) <= 1));
+        assert!(lens.iter().any(|&len| len != 256));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn no_mutations_give_fresh_bytes() {
+        let strategy = arb_mutating::<Len>(10, 0.0);
+
+        assert!(generate_lens(&strategy).into_iter().all(|len| len == 256));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn pool_size_is_respected() {
+        let strategy = arb_mutating::<Len>(5, 0.5);
+        generate_lens(&strategy);
+
+        assert_eq!(5, strategy.pool.lock().unwrap().len());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn bytes_of_all_lengths_can_be_mutated() {
+        let mut runner = TestRunner::deterministic();
+        for len in 0..10 {
+            let mut bytes = vec![0; len];
+            mutate(&mut runner, &mut bytes);
+            assert!(bytes.len().abs_diff(len) <= 1);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[should_panic]
+    fn probability_must_not_exceed_one() {
+        let _ = arb_mutating::<Len>(1, 1.5);
+    }
+}
@@ -0,0 +1,226 @@
+use core::fmt::Debug;
+
+use proptest::strategy::Strategy;
+use proptest::strategy::ValueTree;
+use proptest::test_runner::TestRunner;
+
+use crate::ArbInterop;
+use crate::ArbStrategy;
+use crate::ArbValueTree;
+use crate::arb;
+use crate::assert_probability;
+
+/// A [`proptest::strategy::Strategy`] for [`Option`]s of a given
+/// [`arbitrary::Arbitrary`] type, with a configurable probability of [`None`].
+///
+/// Unlike `arb::<Option<A>>()`, where the [`arbitrary::Arbitrary`]
+/// implementation of [`Option`] decides how often [`None`] comes up, the
+/// probability is chosen by the user. See [`arb_option`].
+#[derive(Clone, PartialEq, PartialOrd, Debug)]
+pub struct ArbOptionStrategy<A: ArbInterop> {
+    strategy: ArbStrategy<A>,
+    none_probability: f64,
+}
+
+impl<A: ArbInterop> ArbOptionStrategy<A> {
+    /// Generates [`None`] with the given `none_probability` and [`Some`] value
+    /// of the given `strategy` otherwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `none_probability` is not in the range `[0.0, 1.0]`.
+    pub fn new(strategy: ArbStrategy<A>, none_probability: f64) -> Self {
+        assert_probability(none_probability);
+
+        Self {
+            strategy,
+            none_probability,
+        }
+    }
+}
+
+impl<A: ArbInterop> Strategy for ArbOptionStrategy<A> {
+    type Tree = ArbOptionValueTree<A>;
+    type Value = Option<A>;
+
+    fn new_tree(&self, run: &mut TestRunner) -> proptest::strategy::NewTree<Self> {
+        let is_none = proptest::bool::weighted(self.none_probability)
+            .new_tree(run)?
+            .current();
+        let inner = if is_none {
+            None
+        } else {
+            Some(self.strategy.new_tree(run)?)
+        };
+
+        Ok(ArbOptionValueTree {
+            inner,
+            is_none,
+            last_step: None,
+        })
+    }
+}
+
+/// The [`proptest::strategy::ValueTree`] of an [`ArbOptionStrategy`].
+///
+/// A [`Some`] value first shrinks its inner value, then tries to become
+/// [`None`].
+#[derive(Debug, Clone)]
+pub struct ArbOptionValueTree<A: Debug> {
+    /// Absent only if [`None`] was generated in the first place.
+    inner: Option<ArbValueTree<A>>,
+    is_none: bool,
+    last_step: Option<OptionStep>,
+}
+
+/// The last simplification of an [`ArbOptionValueTree`], to be undone when
+/// complicating.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum OptionStep {
+    Inner,
+    ToNone,
+}
+
+impl<A: ArbInterop> ValueTree for ArbOptionValueTree<A> {
+    type Value = Option<A>;
+
+    fn current(&self) -> Self::Value {
+        if self.is_none {
+            return None;
+        }
+
+        self.inner.as_ref().map(ValueTree::current)
+    }
+
+    fn simplify(&mut self) -> bool {
+        if self.is_none {
+            return false;
+        }
+        let Some(inner) = &mut self.inner else {
+            return false;
+        };
+
+        if inner.simplify() {
+            self.last_step = Some(OptionStep::Inner);
+            return true;
+        }
+        if self.last_step == Some(OptionStep::ToNone) {
+            return false;
+        }
+
+        self.is_none = true;
+        self.last_step = Some(OptionStep::ToNone);
+        true
+    }
+
+    fn complicate(&mut self) -> bool {
+        match self.last_step {
+            Some(OptionStep::Inner) => self.inner.as_mut().is_some_and(ValueTree::complicate),
+            Some(OptionStep::ToNone) if self.is_none => {
+                self.is_none = false;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Constructs an [`ArbOptionStrategy`] for a given [`arbitrary::Arbitrary`]
+/// type, sized like [`arb`] does, that generates [`None`] with probability
+/// `none_probability`.
+///
+/// # Panics
+///
+/// Panics if `none_probability` is not in the range `[0.0, 1.0]`.
+pub fn arb_option<A: ArbInterop>(none_probability: f64) -> ArbOptionStrategy<A> {
+    ArbOptionStrategy::new(arb(), none_probability)
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, feature(coverage_attribute))]
+mod tests {
+    use proptest::test_runner::Config;
+    use proptest::test_runner::TestError;
+
+    use super::*;
+
+    fn num_nones(none_probability: f64) -> usize {
+        let strategy = arb_option::<u8>(none_probability);
+        let mut runner = TestRunner::deterministic();
+        (0..1000)
+            .filter(|_| strategy.new_tree(&mut runner).unwrap().current().is_none())
+            .count()
+    }
+
+    fn minimal_failure(fails: impl Fn(Option<u8>) -> bool) -> Option<u8> {
+        let config = Config {
+            max_shrink_iters: 1000,
+            ..Config::default()
+        };
+        let mut runner = TestRunner::new_with_rng(config, TestRunner::deterministic().new_rng());
+        let result = runner.run(&arb_option::<u8>(0.0), |value| {
+            proptest::prop_assert!(!fails(value));
+            Ok(())
+        });
+
+        match result {
+            Err(TestError::Fail(_, value)) => value,
+            result => panic!("unexpected result {result:?}"),
+        }
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn none_probability_is_respected() {
+        assert_eq!(0, num_nones(0.0));
+        assert!(num_nones(0.25).abs_diff(250) <= 70);
+        assert_eq!(1000, num_nones(1.0));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn inner_value_shrinks_before_none() {
+        let strategy = ArbOptionStrategy::new(crate::arb_sized::<u8>(4), 0.0);
+        let mut tree = strategy.new_tree(&mut TestRunner::deterministic()).unwrap();
+        let mut values = vec![tree.current()];
+        while tree.simplify() {
+            values.push(tree.current());
+        }
+
+        let (last, rest) = values.split_last().unwrap();
+        assert_eq!(&None, last);
+        assert!(rest.len() > 1);
+        assert!(rest.iter().all(Option::is_some));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn some_stays_if_none_passes() {
+        assert!(minimal_failure(|value| value.is_some()).is_some());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn some_shrinks_to_none() {
+        assert_eq!(None, minimal_failure(|_| true));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn generated_none_does_not_shrink() {
+        let mut tree = arb_option::<u8>(1.0)
+            .new_tree(&mut TestRunner::deterministic())
+            .unwrap();
+
+        assert!(!tree.simplify());
+        assert!(!tree.complicate());
+        assert_eq!(None, tree.current());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[should_panic]
+    fn probability_must_not_exceed_one() {
+        let _ = arb_option::<u8>(1.5);
+    }
+}
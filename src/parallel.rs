@@ -0,0 +1,124 @@
+use proptest::test_runner::Reason;
+use proptest::test_runner::TestRunner;
+use rayon::prelude::*;
+
+use crate::ArbInterop;
+use crate::ArbStrategy;
+use crate::ArbValueTree;
+
+/// The number of candidates for random data searched at once.
+const BATCH_SIZE: usize = 64;
+
+impl<A: ArbInterop> ArbStrategy<A> {
+    /// Fills a batch of candidates for random data, starting with the given
+    /// one, and feeds them to the [`arbitrary::Arbitrary`] implementation in
+    /// parallel. `sample_size` picks the size of every further candidate,
+    /// given the number of candidates before it.
+    ///
+    /// Returns the reasons for rejecting the candidates before the first one
+    /// that is not rejected, and that candidate, if any. Later candidates are
+    /// discarded, as if they were never generated.
+    pub(crate) fn search_in_parallel(
+        &self,
+        run: &mut TestRunner,
+        mut sample_size: impl FnMut(&mut TestRunner, u32) -> Result<usize, Reason>,
+        fill_bytes: &mut impl FnMut(&mut TestRunner, &mut Vec<u8>),
+        first: Vec<u8>,
+    ) -> Result<(Vec<arbitrary::Error>, Option<Vec<u8>>), Reason> {
+        let mut candidates = Vec::with_capacity(BATCH_SIZE);
+        candidates.push(first);
+        for num_earlier in 1..BATCH_SIZE as u32 {
+            let mut bytes = vec![0; sample_size(run, num_earlier)?];
+            fill_bytes(run, &mut bytes);
+            candidates.push(bytes);
+        }
+
+        // Values of type `A` are created and dropped on the worker threads, so
+        // `A` need not be `Send`.
+        let rejections = candidates
+            .par_iter()
+            .map(
+                |bytes| match ArbValueTree::<A>::gen_one_with_size(bytes, bytes.len()) {
+                    Err(
+                        e @ (arbitrary::Error::IncorrectFormat | arbitrary::Error::NotEnoughData),
+                    ) => Some(e),
+                    _ => None,
+                },
+            )
+            .collect::<Vec<_>>();
+
+        let num_rejected = rejections.iter().take_while(|e| e.is_some()).count();
+        let acceptable = candidates.into_iter().nth(num_rejected);
+        let rejections = rejections
+            .into_iter()
+            .take(num_rejected)
+            .flatten()
+            .collect();
+
+        Ok((rejections, acceptable))
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, feature(coverage_attribute))]
+mod tests {
+    use arbitrary::Arbitrary;
+    use proptest::strategy::Strategy;
+
+    use super::*;
+    use crate::ArbConfig;
+    use crate::arb;
+
+    /// Rejects all random data but the one in 16 starting with a byte whose low
+    /// nibble is zero.
+    #[derive(Debug, Clone)]
+    struct Rare;
+
+    impl<'a> Arbitrary<'a> for Rare {
+        fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+            if u8::arbitrary(u)? & 0x0f != 0 {
+                return Err(arbitrary::Error::IncorrectFormat);
+            }
+
+            Ok(Self)
+        }
+    }
+
+    #[test]
+    fn rarely_acceptable_data_is_found() {
+        let mut runner = TestRunner::deterministic();
+        for _ in 0..10 {
+            let tree = arb::<Rare>().new_tree(&mut runner).unwrap();
+            assert_eq!(0, tree.raw_bytes()[0] & 0x0f);
+        }
+    }
+
+    #[test]
+    fn maximum_number_of_retries_is_respected() {
+        let config = ArbConfig::builder().max_retries(3).build();
+        let strategy = ArbStrategy::<Rare>::with_config(config);
+        let mut runner = TestRunner::deterministic();
+        let failures = (0..100)
+            .filter(|_| strategy.new_tree(&mut runner).is_err())
+            .count();
+
+        // Out of four candidates, none is accepted with a probability of 77%.
+        assert!(failures > 60);
+    }
+
+    #[test]
+    fn only_candidates_before_the_acceptable_one_are_rejections() {
+        let strategy = arb::<Rare>();
+        let mut runner = TestRunner::deterministic();
+        let mut fill_bytes = |run: &mut TestRunner, bytes: &mut Vec<u8>| {
+            proptest::prelude::RngCore::fill_bytes(run.rng(), bytes);
+        };
+        let (rejections, acceptable) = strategy
+            .search_in_parallel(&mut runner, |_, _| Ok(4), &mut fill_bytes, vec![1; 4])
+            .unwrap();
+
+        assert!(!rejections.is_empty());
+        assert!(rejections.len() < BATCH_SIZE);
+        assert_eq!(0, acceptable.unwrap()[0] & 0x0f);
+    }
+}
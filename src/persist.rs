@@ -0,0 +1,208 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::string::String;
+
+use crate::ArbConfig;
+use crate::ArbInterop;
+use crate::ArbStrategy;
+use crate::SizeMode;
+
+/// Why [`ArbStrategy::from_toml`] failed.
+#[derive(Debug)]
+pub enum ConfigError {
+    /// The input is no valid TOML, or no strategy configuration.
+    Parse(toml::de::Error),
+
+    /// The configuration is for a strategy of a different type.
+    TypeMismatch {
+        /// The name of the type the configuration is loaded for.
+        expected: &'static str,
+
+        /// The name of the type the configuration was saved for.
+        found: String,
+    },
+}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Parse(e) => write!(f, "invalid strategy configuration: {e}"),
+            Self::TypeMismatch { expected, found } => {
+                write!(
+                    f,
+                    "configuration for type `{found}` loaded for `{expected}`"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Parse(e) => Some(e),
+            Self::TypeMismatch { .. } => None,
+        }
+    }
+}
+
+/// The TOML representation of an [`ArbStrategy`]. Settings at their default
+/// are left out.
+#[derive(serde::Serialize, serde::Deserialize)]
+struct StoredStrategy {
+    #[serde(rename = "type")]
+    type_name: String,
+    size_mode: SizeMode,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    max_retries: Option<u32>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    shrink_max_steps: Option<usize>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    max_cases: Option<u32>,
+
+    #[serde(default, skip_serializing_if = "core::ops::Not::not")]
+    verbose_bytes: bool,
+}
+
+impl<A: ArbInterop> ArbStrategy<A> {
+    /// Saves the configuration of this strategy as TOML, for example so that
+    /// CI can reuse the configuration of a fuzzing campaign that found bugs.
+    ///
+    /// Includes the name of type `A`, to validate it when
+    /// [loading](Self::from_toml), the size of the random data, and all other
+    /// settings that differ from their default. The
+    /// [name](Self::new_named) and [source location](Self::source_location)
+    /// are not saved.
+    ///
+    /// ```
+    /// # use proptest_arbitrary_adapter::ArbConfig;
+    /// # use proptest_arbitrary_adapter::ArbStrategy;
+    /// let config = ArbConfig::builder().size(8).max_retries(3).build();
+    /// let strategy = ArbStrategy::<u32>::with_config(config);
+    /// let toml = strategy.to_toml();
+    ///
+    /// assert_eq!(strategy, ArbStrategy::from_toml(&toml).unwrap());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if a size or limit exceeds [`i64::MAX`], which TOML cannot
+    /// represent.
+    pub fn to_toml(&self) -> String {
+        let default = ArbConfig::default();
+        let config = self.config;
+        let stored = StoredStrategy {
+            type_name: core::any::type_name::<A>().into(),
+            size_mode: config.size_mode,
+            max_retries: (config.max_retries != default.max_retries).then_some(config.max_retries),
+            shrink_max_steps: (config.shrink_max_steps != default.shrink_max_steps)
+                .then_some(config.shrink_max_steps),
+            max_cases: config.max_cases,
+            verbose_bytes: config.verbose_bytes,
+        };
+
+        toml::to_string(&stored).expect("strategy configuration must be representable in TOML")
+    }
+
+    /// Loads a strategy configuration previously saved with
+    /// [`to_toml`](Self::to_toml). Missing settings take their default.
+    ///
+    /// Fails with [`ConfigError::TypeMismatch`] if the configuration was saved
+    /// for a type other than `A`.
+    pub fn from_toml(s: &str) -> Result<Self, ConfigError> {
+        let stored = toml::from_str::<StoredStrategy>(s).map_err(ConfigError::Parse)?;
+        let expected = core::any::type_name::<A>();
+        if stored.type_name != expected {
+            return Err(ConfigError::TypeMismatch {
+                expected,
+                found: stored.type_name,
+            });
+        }
+
+        let default = ArbConfig::default();
+        let config = ArbConfig {
+            size_mode: stored.size_mode,
+            max_retries: stored.max_retries.unwrap_or(default.max_retries),
+            shrink_max_steps: stored.shrink_max_steps.unwrap_or(default.shrink_max_steps),
+            max_cases: stored.max_cases,
+            verbose_bytes: stored.verbose_bytes,
+        };
+
+        Ok(Self::with_config(config))
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, feature(coverage_attribute))]
+mod tests {
+    use super::*;
+    use crate::arb_sized;
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn configurations_round_trip() {
+        let strategies = [
+            arb_sized::<u32>(8),
+            ArbStrategy::with_size_range(1, 100),
+            ArbStrategy::with_config(
+                ArbConfig::builder()
+                    .size_mode(SizeMode::Bimodal {
+                        base: 4,
+                        recursive: 64,
+                        recursive_per_mille: 250,
+                    })
+                    .max_retries(3)
+                    .shrink_max_steps(10)
+                    .max_cases(100)
+                    .verbose_bytes(true)
+                    .build(),
+            ),
+        ];
+
+        for strategy in strategies {
+            let toml = strategy.to_toml();
+            assert_eq!(strategy, ArbStrategy::from_toml(&toml).unwrap(), "{toml}");
+        }
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn only_non_default_settings_are_saved() {
+        let toml = arb_sized::<u32>(8).to_toml();
+
+        assert!(toml.contains("type = \"u32\""));
+        assert!(toml.contains("Fixed = 8"));
+        assert!(!toml.contains("max_retries"));
+
+        let config = ArbConfig::builder().size(8).max_retries(3).build();
+        let toml = ArbStrategy::<u32>::with_config(config).to_toml();
+        assert!(toml.contains("max_retries = 3"));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn other_types_are_rejected() {
+        let toml = arb_sized::<u32>(8).to_toml();
+        let error = ArbStrategy::<u8>::from_toml(&toml).unwrap_err();
+
+        assert!(matches!(
+            &error,
+            ConfigError::TypeMismatch { expected: "u8", found } if found == "u32"
+        ));
+        assert_eq!(
+            "configuration for type `u32` loaded for `u8`",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn invalid_toml_is_rejected() {
+        let error = ArbStrategy::<u8>::from_toml("size_mode = 8").unwrap_err();
+
+        assert!(matches!(error, ConfigError::Parse(_)));
+    }
+}
@@ -0,0 +1,157 @@
+//! Everything needed to use this crate in the common case.
+//!
+//! ```
+//! use proptest::prelude::*;
+//! use proptest_arbitrary_adapter::prelude::*;
+//!
+//! let mut runner = TestRunner::default();
+//! let tree = arb::<u8>().new_tree(&mut runner).unwrap();
+//! ```
+//!
+//! Besides all public items of this crate, the prelude re-exports the
+//! [`proptest`] items [`Strategy`], [`BoxedStrategy`], [`TestRunner`], and
+//! [`ValueTree`]. It contains nothing that conflicts with
+//! [`proptest::prelude`], so both can be glob-imported together.
+
+pub use proptest::strategy::BoxedStrategy;
+pub use proptest::strategy::Strategy;
+pub use proptest::strategy::ValueTree;
+pub use proptest::test_runner::TestRunner;
+
+pub use crate::AnnotatedArbStrategy;
+pub use crate::ArbBoxed;
+pub use crate::ArbConfig;
+pub use crate::ArbConfigBuilder;
+pub use crate::ArbInterop;
+pub use crate::ArbIter;
+pub use crate::ArbNoShrink;
+pub use crate::ArbNoShrinkTree;
+pub use crate::ArbOptionStrategy;
+pub use crate::ArbOptionValueTree;
+pub use crate::ArbResultStrategy;
+pub use crate::ArbResultValueTree;
+pub use crate::ArbStrategy;
+pub use crate::ArbValueTree;
+pub use crate::BatchArbStrategy;
+pub use crate::BatchValueTree;
+pub use crate::BiasedArbStrategy;
+pub use crate::BinarySearchShrinkStrategy;
+pub use crate::BoundedArbStrategy;
+#[cfg(feature = "std")]
+pub use crate::CachedArbStrategy;
+pub use crate::ChainArbStrategy;
+#[cfg(feature = "std")]
+pub use crate::CheckedArbStrategy;
+#[cfg(feature = "toml")]
+pub use crate::ConfigError;
+#[cfg(feature = "std")]
+pub use crate::CorpusArbStrategy;
+#[cfg(feature = "std")]
+pub use crate::CoverageArbStrategy;
+pub use crate::DictionaryArbStrategy;
+pub use crate::EntropyArbStrategy;
+pub use crate::ExhaustiveArbStrategy;
+pub use crate::Failure;
+pub use crate::FailureSummary;
+pub use crate::FixedBytesArbStrategy;
+pub use crate::GenerationInfo;
+pub use crate::GrowingArbStrategy;
+pub use crate::IndexedArbStrategy;
+pub use crate::IndexedValueTree;
+pub use crate::InspectBytesStrategy;
+pub use crate::InspectStrategy;
+pub use crate::InterleaveArbStrategy;
+#[cfg(feature = "std")]
+pub use crate::IteratorArbStrategy;
+pub use crate::LinearTruncateShrinkStrategy;
+pub use crate::MapBytesArbStrategy;
+#[cfg(feature = "std")]
+pub use crate::MutatingArbStrategy;
+pub use crate::PrioritizedArbStrategy;
+#[cfg(feature = "quickcheck")]
+pub use crate::QcArbInterop;
+#[cfg(feature = "quickcheck")]
+pub use crate::QcArbStrategy;
+#[cfg(feature = "quickcheck")]
+pub use crate::QcValueTree;
+#[cfg(feature = "std")]
+pub use crate::RecordingArbStrategy;
+#[cfg(feature = "std")]
+pub use crate::RecordingArbValueTree;
+pub use crate::ReplayArbStrategy;
+#[cfg(feature = "std")]
+pub use crate::SeededArbStrategy;
+pub use crate::ShrinkState;
+pub use crate::ShrinkStrategy;
+pub use crate::ShrinkingArbStrategy;
+pub use crate::ShuffledArbStrategy;
+pub use crate::SizeMode;
+#[cfg(feature = "std")]
+pub use crate::SizeStats;
+#[cfg(feature = "stats")]
+pub use crate::StatsArbStrategy;
+#[cfg(feature = "std")]
+pub use crate::StructuredMutationArbStrategy;
+#[cfg(feature = "std")]
+pub use crate::ThreadLocalArbStrategy;
+pub use crate::UnionArbStrategy;
+pub use crate::UnionValueTree;
+pub use crate::ValidatedArbStrategy;
+pub use crate::WeightedSizeArbStrategy;
+pub use crate::ZeroArbValueTree;
+pub use crate::ZeroBytesShrinkStrategy;
+pub use crate::arb;
+pub use crate::arb_and_then;
+pub use crate::arb_biased;
+pub use crate::arb_binary_search;
+pub use crate::arb_bounded;
+pub use crate::arb_by_index;
+#[cfg(feature = "std")]
+pub use crate::arb_cached;
+pub use crate::arb_chain;
+#[cfg(feature = "std")]
+pub use crate::arb_checked;
+#[cfg(feature = "std")]
+pub use crate::arb_coverage;
+#[cfg(feature = "std")]
+pub use crate::arb_from_iterator;
+pub use crate::arb_growing;
+pub use crate::arb_growing_sized;
+pub use crate::arb_here;
+pub use crate::arb_interleave;
+pub use crate::arb_just;
+#[cfg(feature = "std")]
+pub use crate::arb_mutating;
+pub use crate::arb_named;
+pub use crate::arb_no_shrink;
+pub use crate::arb_one;
+pub use crate::arb_one_seeded;
+pub use crate::arb_option;
+pub use crate::arb_prioritized;
+pub use crate::arb_probed;
+pub use crate::arb_recursive;
+pub use crate::arb_recursive_strategy;
+pub use crate::arb_replay;
+#[cfg(feature = "std")]
+pub use crate::arb_replay_from_file;
+pub use crate::arb_result;
+#[cfg(feature = "std")]
+pub use crate::arb_seed;
+pub use crate::arb_size_hint_at_depth;
+pub use crate::arb_sized;
+pub use crate::arb_sized_named;
+pub use crate::arb_sized_no_shrink;
+#[cfg(feature = "std")]
+pub use crate::arb_structured_mutation;
+#[cfg(feature = "std")]
+pub use crate::arb_thread_local;
+pub use crate::arb_tuple;
+pub use crate::arb_vec;
+pub use crate::arb_vec_of_size;
+pub use crate::arb_weighted_sizes;
+pub use crate::arb_with_bytes;
+pub use crate::arb_with_dictionary;
+pub use crate::arb_with_entropy;
+#[cfg(feature = "quickcheck")]
+pub use crate::qc_arb;
+pub use crate::size_hint_bytes;
@@ -0,0 +1,175 @@
+use proptest::prelude::RngCore;
+use proptest::test_runner::TestRunner;
+
+use crate::ArbInterop;
+use crate::ArbStrategy;
+use crate::ArbValueTree;
+use crate::arb;
+
+/// A [`proptest::strategy::Strategy`] that tries small buffers of random data
+/// first: for every test case, it doubles the size from some minimum up to
+/// some maximum, using the first size the [`arbitrary::Arbitrary`]
+/// implementation accepts. See [`arb_prioritized`].
+///
+/// Useful for types whose bugs small values are likely to trigger. Unlike
+/// [`GrowingArbStrategy`](crate::GrowingArbStrategy), every test case starts
+/// over at the minimum size, and trying larger sizes does not count as a
+/// rejection. Only if even the maximum size is rejected does the test case
+/// count as rejected and start over. Like [`arb`], gives up after the default
+/// number of [`max_retries`](crate::ArbConfig::max_retries) such rejections.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct PrioritizedArbStrategy<A: ArbInterop> {
+    min_size: usize,
+    max_size: usize,
+
+    /// Names the strategy in failure messages, limits the number of
+    /// rejections and configures shrinking. Its sizes are unused.
+    strategy: ArbStrategy<A>,
+}
+
+impl<A: ArbInterop> PrioritizedArbStrategy<A> {
+    /// # Panics
+    ///
+    /// Panics if `min_size` is greater than `max_size`.
+    pub fn new(min_size: usize, max_size: usize) -> Self {
+        assert!(
+            min_size <= max_size,
+            "minimum size {min_size} exceeds maximum size {max_size}"
+        );
+
+        Self {
+            min_size,
+            max_size,
+            strategy: arb(),
+        }
+    }
+}
+
+impl<A: ArbInterop> proptest::strategy::Strategy for PrioritizedArbStrategy<A> {
+    type Tree = ArbValueTree<A>;
+    type Value = A;
+
+    fn new_tree(&self, run: &mut TestRunner) -> proptest::strategy::NewTree<Self> {
+        // Try ever longer prefixes of random data of the maximum size. Only if
+        // even the whole random data is rejected does generation start over.
+        self.strategy.new_tree_sized(
+            run,
+            |_, _| Ok(self.max_size),
+            |run, bytes| run.rng().fill_bytes(bytes),
+            || (),
+            |bytes| {
+                let mut size = self.min_size;
+                loop {
+                    match ArbValueTree::new(bytes[..size].to_vec()) {
+                        Err(
+                            arbitrary::Error::IncorrectFormat | arbitrary::Error::NotEnoughData,
+                        ) if size < self.max_size => {
+                            size = size.saturating_mul(2).max(1).min(self.max_size);
+                        }
+                        built => return built,
+                    }
+                }
+            },
+        )
+    }
+}
+
+/// Constructs a [`PrioritizedArbStrategy`] for a given
+/// [`arbitrary::Arbitrary`] type, which tries `min_size`, twice that, and so
+/// on, up to `max_size` bytes of random data for every test case.
+///
+/// # Panics
+///
+/// Panics if `min_size` is greater than `max_size`.
+pub fn arb_prioritized<A: ArbInterop>(
+    min_size: usize,
+    max_size: usize,
+) -> PrioritizedArbStrategy<A> {
+    PrioritizedArbStrategy::new(min_size, max_size)
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, feature(coverage_attribute))]
+mod tests {
+    use arbitrary::Arbitrary;
+    use proptest::prelude::*;
+    use proptest::strategy::ValueTree;
+
+    use super::*;
+
+    /// Requires at least 100 bytes to be generated from.
+    #[derive(Debug, Clone)]
+    struct Large(usize);
+
+    impl<'a> Arbitrary<'a> for Large {
+        fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+            if u.len() < 100 {
+                return Err(arbitrary::Error::NotEnoughData);
+            }
+
+            Ok(Self(u.len()))
+        }
+    }
+
+    fn runner_without_rejections() -> TestRunner {
+        TestRunner::new(ProptestConfig {
+            max_local_rejects: 0,
+            ..ProptestConfig::default()
+        })
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn smallest_accepted_size_is_used() {
+        let strategy = arb_prioritized::<Large>(8, 1024);
+        let mut runner = runner_without_rejections();
+        for _ in 0..10 {
+            assert_eq!(128, strategy.new_tree(&mut runner).unwrap().current().0);
+        }
+
+        let tree = arb_prioritized::<u8>(8, 1024)
+            .new_tree(&mut runner)
+            .unwrap();
+        assert_eq!(8, tree.raw_bytes().len());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn sizes_are_capped() {
+        let strategy = arb_prioritized::<Large>(0, 100);
+        let tree = strategy.new_tree(&mut runner_without_rejections()).unwrap();
+
+        assert_eq!(100, tree.current().0);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn rejected_maximum_size_is_a_rejection() {
+        let strategy = arb_prioritized::<Large>(8, 64);
+        let mut runner = TestRunner::new(ProptestConfig {
+            max_local_rejects: 100,
+            ..ProptestConfig::default()
+        });
+
+        assert!(strategy.new_tree(&mut runner).is_err());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn too_many_rejections_fail() {
+        let strategy = arb_prioritized::<Large>(8, 64);
+        let reason = strategy
+            .new_tree(&mut TestRunner::deterministic())
+            .unwrap_err();
+
+        let expected = format!("{}: too many rejections", core::any::type_name::<Large>());
+        assert!(reason.message().starts_with(&expected));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[should_panic]
+    fn minimum_size_must_not_exceed_maximum_size() {
+        let _ = arb_prioritized::<Large>(16, 8);
+    }
+}
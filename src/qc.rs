@@ -0,0 +1,183 @@
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::marker::PhantomData;
+
+use proptest::prelude::RngCore;
+use proptest::strategy::Strategy;
+use proptest::strategy::ValueTree;
+use proptest::test_runner::TestRunner;
+use quickcheck::Arbitrary;
+use quickcheck::Gen;
+
+/// The size used by [`qc_arb`], which is also quickcheck's default size.
+const DEFAULT_SIZE: usize = 100;
+
+/// The [`quickcheck::Arbitrary`] implementations that this crate works with.
+/// Like [`ArbInterop`](trait@crate::ArbInterop), but for quickcheck.
+pub trait QcArbInterop: Arbitrary + Debug {}
+impl<A> QcArbInterop for A where A: Arbitrary + Debug {}
+
+/// A [`proptest::strategy::Strategy`] for a given [`quickcheck::Arbitrary`]
+/// type. See [`qc_arb`].
+///
+/// Values are generated from a [`quickcheck::Gen`] seeded by the
+/// [`TestRunner`]'s random number generator, which keeps test runs
+/// reproducible. Shrinking delegates to [`quickcheck::Arbitrary::shrink`].
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct QcArbStrategy<A: QcArbInterop> {
+    /// The size of the [`quickcheck::Gen`], which, for example, bounds the
+    /// length of generated collections.
+    size: usize,
+    _ph: PhantomData<fn() -> A>,
+}
+
+impl<A: QcArbInterop> QcArbStrategy<A> {
+    /// Generates values from a [`quickcheck::Gen`] of the given `size`.
+    pub fn new(size: usize) -> Self {
+        Self {
+            size,
+            _ph: PhantomData,
+        }
+    }
+}
+
+impl<A: QcArbInterop> Strategy for QcArbStrategy<A> {
+    type Tree = QcValueTree<A>;
+    type Value = A;
+
+    fn new_tree(&self, run: &mut TestRunner) -> proptest::strategy::NewTree<Self> {
+        let mut generator = Gen::from_size_and_seed(self.size, run.rng().next_u64());
+        let curr = A::arbitrary(&mut generator);
+
+        Ok(QcValueTree {
+            shrinker: curr.shrink(),
+            curr,
+            prev: None,
+        })
+    }
+}
+
+/// The [`proptest::strategy::ValueTree`] of a [`QcArbStrategy`].
+pub struct QcValueTree<A: QcArbInterop> {
+    curr: A,
+
+    /// The remaining candidates to simplify to. All of them are simpler than
+    /// the last value known to reproduce the failure.
+    shrinker: Box<dyn Iterator<Item = A>>,
+
+    /// The last value known to reproduce the failure, if `curr` is a candidate
+    /// yet to be judged.
+    prev: Option<A>,
+}
+
+impl<A: QcArbInterop> Debug for QcValueTree<A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("QcValueTree")
+            .field("curr", &self.curr)
+            .field("prev", &self.prev)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<A: QcArbInterop> ValueTree for QcValueTree<A> {
+    type Value = A;
+
+    fn current(&self) -> Self::Value {
+        self.curr.clone()
+    }
+
+    fn simplify(&mut self) -> bool {
+        // Simplification is only requested if the current value reproduces the
+        // failure. If it is a candidate, shrink it further from now on.
+        if self.prev.take().is_some() {
+            self.shrinker = self.curr.shrink();
+        }
+
+        let Some(candidate) = self.shrinker.next() else {
+            return false;
+        };
+        self.prev = Some(std::mem::replace(&mut self.curr, candidate));
+
+        true
+    }
+
+    fn complicate(&mut self) -> bool {
+        // The candidate does not reproduce the failure. Go back to the last
+        // value that did, and try its next candidate when simplifying.
+        let Some(prev) = self.prev.take() else {
+            return false;
+        };
+        self.curr = prev;
+
+        true
+    }
+}
+
+/// Constructs a [`QcArbStrategy`] for a given [`quickcheck::Arbitrary`] type,
+/// using quickcheck's default size of 100.
+pub fn qc_arb<A: QcArbInterop>() -> QcArbStrategy<A> {
+    QcArbStrategy::new(DEFAULT_SIZE)
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, feature(coverage_attribute))]
+mod tests {
+    use proptest::prop_assert;
+    use proptest::test_runner::Config;
+    use proptest::test_runner::TestError;
+
+    use super::*;
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn generation_is_reproducible() {
+        let generate = || {
+            qc_arb::<Vec<u32>>()
+                .new_tree(&mut TestRunner::deterministic())
+                .unwrap()
+                .current()
+        };
+
+        assert_eq!(generate(), generate());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn size_bounds_collections() {
+        let strategy = QcArbStrategy::<Vec<u8>>::new(5);
+        let mut runner = TestRunner::deterministic();
+        for _ in 0..100 {
+            assert!(strategy.new_tree(&mut runner).unwrap().current().len() <= 5);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn failures_shrink_like_quickcheck() {
+        let config = Config {
+            max_shrink_iters: 10_000,
+            ..Config::default()
+        };
+        let mut runner = TestRunner::new_with_rng(config, TestRunner::deterministic().new_rng());
+        let result = runner.run(&qc_arb::<Vec<u32>>(), |numbers| {
+            prop_assert!(numbers.iter().all(|&n| n < 1000));
+            Ok(())
+        });
+
+        let Err(TestError::Fail(_, numbers)) = result else {
+            panic!("expected failure, got {result:?}");
+        };
+        assert_eq!(vec![1000], numbers);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn exhausted_tree_does_not_complicate() {
+        let mut tree = qc_arb::<()>()
+            .new_tree(&mut TestRunner::deterministic())
+            .unwrap();
+
+        assert!(!tree.simplify());
+        assert!(!tree.complicate());
+    }
+}
@@ -0,0 +1,161 @@
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::vec::Vec;
+
+use proptest::strategy::Strategy;
+use proptest::strategy::ValueTree;
+use proptest::test_runner::TestRunner;
+
+use crate::ArbInterop;
+use crate::ArbStrategy;
+use crate::ArbValueTree;
+use crate::GenerationInfo;
+use crate::lock;
+
+/// A [`proptest::strategy::Strategy`] like [`ArbStrategy`] that records the
+/// [`GenerationInfo`] of every test case, for post-mortem analysis of
+/// shrinking performance. See [`ArbStrategy::with_generation_infos`].
+///
+/// The info of a test case is recorded when proptest drops its
+/// [`RecordingArbValueTree`], that is, once it is done generating and shrinking
+/// the value. Other strategies, even of the same test runner, do not record
+/// anything. Recording is opt-in, since counting the bytes consumed generates
+/// the value anew.
+///
+/// ```
+/// # use proptest::test_runner::Config;
+/// # use proptest::test_runner::TestRunner;
+/// # use proptest_arbitrary_adapter::arb;
+/// let strategy = arb::<u8>().with_generation_infos();
+/// let mut runner = TestRunner::new(Config::with_cases(10));
+/// runner.run(&strategy, |_| Ok(())).unwrap();
+///
+/// assert_eq!(10, strategy.generation_infos().len());
+/// ```
+///
+/// Clones of the strategy share the recorded infos.
+#[derive(Clone, Debug)]
+pub struct RecordingArbStrategy<A: ArbInterop> {
+    strategy: ArbStrategy<A>,
+    recorded: Arc<Mutex<Vec<GenerationInfo>>>,
+}
+
+impl<A: ArbInterop> RecordingArbStrategy<A> {
+    /// The infos recorded since the strategy was created or the infos were
+    /// last taken. Recording continues.
+    pub fn generation_infos(&self) -> Vec<GenerationInfo> {
+        core::mem::take(&mut *lock(&self.recorded))
+    }
+}
+
+impl<A: ArbInterop> ArbStrategy<A> {
+    /// Turns this strategy into a [`RecordingArbStrategy`], which records the
+    /// [`GenerationInfo`] of every test case.
+    pub fn with_generation_infos(self) -> RecordingArbStrategy<A> {
+        RecordingArbStrategy {
+            strategy: self,
+            recorded: Arc::default(),
+        }
+    }
+}
+
+impl<A: ArbInterop> Strategy for RecordingArbStrategy<A> {
+    type Tree = RecordingArbValueTree<A>;
+    type Value = A;
+
+    fn new_tree(&self, run: &mut TestRunner) -> proptest::strategy::NewTree<Self> {
+        Ok(RecordingArbValueTree {
+            tree: self.strategy.new_tree(run)?,
+            recorded: Arc::clone(&self.recorded),
+        })
+    }
+}
+
+/// The [`proptest::strategy::ValueTree`] of a [`RecordingArbStrategy`]. Shrinks
+/// like an [`ArbValueTree`], and records its [`GenerationInfo`] when dropped.
+#[derive(Debug)]
+pub struct RecordingArbValueTree<A: ArbInterop> {
+    tree: ArbValueTree<A>,
+    recorded: Arc<Mutex<Vec<GenerationInfo>>>,
+}
+
+impl<A: ArbInterop> ValueTree for RecordingArbValueTree<A> {
+    type Value = A;
+
+    fn current(&self) -> Self::Value {
+        self.tree.current()
+    }
+
+    fn simplify(&mut self) -> bool {
+        self.tree.simplify()
+    }
+
+    fn complicate(&mut self) -> bool {
+        self.tree.complicate()
+    }
+}
+
+impl<A: ArbInterop> Drop for RecordingArbValueTree<A> {
+    fn drop(&mut self) {
+        let info = self.tree.generation_info();
+        lock(&self.recorded).push(info);
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, feature(coverage_attribute))]
+mod tests {
+    use proptest::test_runner::Config;
+    use proptest::test_runner::TestCaseError;
+
+    use super::*;
+    use crate::arb_sized;
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn infos_of_all_test_cases_are_recorded() {
+        let strategy = arb_sized::<u8>(4).with_generation_infos();
+        let mut runner = TestRunner::new(Config::with_cases(10));
+        runner.run(&strategy, |_| Ok(())).unwrap();
+
+        let infos = strategy.generation_infos();
+        assert_eq!(10, infos.len());
+        assert!(infos.iter().all(|info| info.buffer_size == 4));
+        assert!(strategy.generation_infos().is_empty());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn shrinking_is_recorded() {
+        let strategy = arb_sized::<u8>(4).with_generation_infos();
+        let mut runner = TestRunner::new(Config::with_cases(1));
+        let _ = runner.run(&strategy, |_| Err(TestCaseError::fail("")));
+
+        let infos = strategy.generation_infos();
+        assert_eq!(1, infos.len());
+        assert!(infos[0].simplify_steps > 0);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn only_the_recording_strategy_records() {
+        let strategy = arb_sized::<u8>(4).with_generation_infos();
+        let mut runner = TestRunner::new(Config::with_cases(10));
+        runner
+            .run(&(strategy.clone(), arb_sized::<u8>(4)), |_| Ok(()))
+            .unwrap();
+        runner.run(&arb_sized::<u8>(4), |_| Ok(())).unwrap();
+
+        assert_eq!(10, strategy.generation_infos().len());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn clones_share_the_infos() {
+        let strategy = arb_sized::<u8>(4).with_generation_infos();
+        let clone = strategy.clone();
+        drop(clone.new_tree(&mut TestRunner::deterministic()).unwrap());
+
+        assert_eq!(1, strategy.generation_infos().len());
+    }
+}
@@ -0,0 +1,221 @@
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::sync::atomic::AtomicUsize;
+use core::sync::atomic::Ordering;
+
+use proptest::prelude::RngCore;
+use proptest::strategy::Strategy;
+use proptest::test_runner::TestRunner;
+
+use crate::ArbInterop;
+use crate::ArbStrategy;
+use crate::ArbValueTree;
+use crate::arb;
+
+/// A [`proptest::strategy::Strategy`] that replays a recorded sequence of
+/// random data, one buffer per attempt to generate a value, for deterministic
+/// regression tests that survive changes to proptest's seeds. See
+/// [`arb_replay`].
+///
+/// After the last buffer, the sequence starts over. Buffers the
+/// [`arbitrary::Arbitrary`] implementation rejects are skipped like rejected
+/// random data. Without any buffers, the strategy generates random data like
+/// [`arb`] does. Values shrink as usual.
+///
+/// Clones of the strategy share the position in the sequence.
+#[derive(Clone, Debug)]
+pub struct ReplayArbStrategy<A: ArbInterop> {
+    strategy: ArbStrategy<A>,
+    buffers: Vec<Vec<u8>>,
+
+    /// The index of the next buffer to replay, modulo the number of buffers.
+    next_index: Arc<AtomicUsize>,
+}
+
+impl<A: ArbInterop> ReplayArbStrategy<A> {
+    /// Replays the given `buffers` through the given `strategy`, which only
+    /// contributes its configuration, like the number of shrinking steps.
+    pub fn new(strategy: ArbStrategy<A>, buffers: Vec<Vec<u8>>) -> Self {
+        Self {
+            strategy,
+            buffers,
+            next_index: Arc::default(),
+        }
+    }
+}
+
+impl<A: ArbInterop> Strategy for ReplayArbStrategy<A> {
+    type Tree = ArbValueTree<A>;
+    type Value = A;
+
+    fn new_tree(&self, run: &mut TestRunner) -> proptest::strategy::NewTree<Self> {
+        self.strategy.new_tree_with(run, |run, bytes| {
+            if self.buffers.is_empty() {
+                run.rng().fill_bytes(bytes);
+                return;
+            }
+
+            let index = self.next_index.fetch_add(1, Ordering::Relaxed);
+            bytes.clone_from(&self.buffers[index % self.buffers.len()]);
+        })
+    }
+}
+
+/// Constructs a [`ReplayArbStrategy`] for a given [`arbitrary::Arbitrary`]
+/// type that replays the given `buffers` of random data in order.
+///
+/// ```
+/// # use proptest::strategy::Strategy;
+/// # use proptest::strategy::ValueTree;
+/// # use proptest::test_runner::TestRunner;
+/// # use proptest_arbitrary_adapter::arb_replay;
+/// let strategy = arb_replay::<u8>(vec![vec![1], vec![2]]);
+/// let mut runner = TestRunner::deterministic();
+/// assert_eq!(1, strategy.new_tree(&mut runner).unwrap().current());
+/// assert_eq!(2, strategy.new_tree(&mut runner).unwrap().current());
+/// assert_eq!(1, strategy.new_tree(&mut runner).unwrap().current());
+/// ```
+pub fn arb_replay<A: ArbInterop>(buffers: Vec<Vec<u8>>) -> ReplayArbStrategy<A> {
+    ReplayArbStrategy::new(arb(), buffers)
+}
+
+/// Like [`arb_replay`], but reads the buffers from the file at the given
+/// `path`, one per line in hexadecimal, like
+/// [`ArbValueTree::bytes_as_hex`] formats them. An empty line stands for an
+/// empty buffer.
+///
+/// # Errors
+///
+/// Fails if the file cannot be read, or with
+/// [`InvalidData`](std::io::ErrorKind::InvalidData) if a line is not
+/// hexadecimal.
+#[cfg(feature = "std")]
+pub fn arb_replay_from_file<A: ArbInterop>(
+    path: &std::path::Path,
+) -> std::io::Result<ReplayArbStrategy<A>> {
+    let buffers = std::fs::read_to_string(path)?
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            from_hex(line.trim()).ok_or_else(|| {
+                let message = std::format!("{}:{}: not hexadecimal", path.display(), i + 1);
+                std::io::Error::new(std::io::ErrorKind::InvalidData, message)
+            })
+        })
+        .collect::<std::io::Result<_>>()?;
+
+    Ok(arb_replay(buffers))
+}
+
+/// Parses pairs of hexadecimal digits, in either case, into bytes.
+#[cfg(feature = "std")]
+fn from_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) {
+        return None;
+    }
+
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, feature(coverage_attribute))]
+mod tests {
+    use alloc::vec;
+
+    use proptest::strategy::ValueTree;
+
+    use super::*;
+
+    fn generate(strategy: &ReplayArbStrategy<u8>, n: usize) -> Vec<u8> {
+        let mut runner = TestRunner::deterministic();
+        (0..n)
+            .map(|_| strategy.new_tree(&mut runner).unwrap().current())
+            .collect()
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn buffers_are_replayed_cyclically() {
+        let strategy = arb_replay::<u8>(vec![vec![1], vec![2], vec![3]]);
+
+        assert_eq!(vec![1, 2, 3, 1, 2], generate(&strategy, 5));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn clones_share_the_position() {
+        let strategy = arb_replay::<u8>(vec![vec![1], vec![2]]);
+        generate(&strategy.clone(), 1);
+
+        assert_eq!(vec![2], generate(&strategy, 1));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn rejected_buffers_are_skipped() {
+        /// Rejects random data with a zero first byte.
+        #[derive(Debug, Clone)]
+        struct NonZero(u8);
+
+        impl<'a> arbitrary::Arbitrary<'a> for NonZero {
+            fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+                match u8::arbitrary(u)? {
+                    0 => Err(arbitrary::Error::IncorrectFormat),
+                    byte => Ok(Self(byte)),
+                }
+            }
+        }
+
+        let strategy = arb_replay::<NonZero>(vec![vec![0], vec![7]]);
+        let tree = strategy.new_tree(&mut TestRunner::deterministic()).unwrap();
+
+        assert_eq!(7, tree.current().0);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn no_buffers_give_random_data() {
+        let values = generate(&arb_replay::<u8>(Vec::new()), 100);
+
+        assert!(values.iter().any(|value| *value != values[0]));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn replayed_values_shrink_as_usual() {
+        let mut tree = arb_replay::<u8>(vec![vec![200]])
+            .new_tree(&mut TestRunner::deterministic())
+            .unwrap();
+        while tree.simplify() {}
+
+        assert_eq!(0, tree.current());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn hex_is_parsed() {
+        assert_eq!(Some(vec![0xde, 0xad, 0xbe, 0xef]), from_hex("deADbeef"));
+        assert_eq!(Some(vec![]), from_hex(""));
+        assert_eq!(None, from_hex("abc"));
+        assert_eq!(None, from_hex("zz"));
+    }
+
+    // Not on `wasm32`, which has no file system.
+    #[cfg(feature = "std")]
+    #[test]
+    fn buffers_are_read_from_file() {
+        let path = std::env::temp_dir().join(format!("replay-{}.txt", std::process::id()));
+        std::fs::write(&path, "01\n\nff\n").unwrap();
+        let strategy = arb_replay_from_file::<u8>(&path).unwrap();
+        assert_eq!(vec![1, 0, 255], generate(&strategy, 3));
+
+        std::fs::write(&path, "01\nxy\n").unwrap();
+        let error = arb_replay_from_file::<u8>(&path).unwrap_err();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(std::io::ErrorKind::InvalidData, error.kind());
+    }
+}
@@ -0,0 +1,162 @@
+use core::fmt::Debug;
+
+use proptest::strategy::Strategy;
+use proptest::strategy::ValueTree;
+use proptest::test_runner::TestRunner;
+
+use crate::ArbInterop;
+use crate::ArbStrategy;
+use crate::ArbValueTree;
+use crate::arb;
+use crate::assert_probability;
+
+/// A [`proptest::strategy::Strategy`] for [`Result`]s of given
+/// [`arbitrary::Arbitrary`] types, with a configurable probability of [`Err`].
+///
+/// Unlike `arb::<Result<O, E>>()`, where the [`arbitrary::Arbitrary`]
+/// implementation of [`Result`] decides how often [`Err`] comes up, the
+/// probability is chosen by the user. See [`arb_result`].
+#[derive(Clone, PartialEq, PartialOrd, Debug)]
+pub struct ArbResultStrategy<O: ArbInterop, E: ArbInterop> {
+    ok: ArbStrategy<O>,
+    err: ArbStrategy<E>,
+    err_probability: f64,
+}
+
+impl<O: ArbInterop, E: ArbInterop> ArbResultStrategy<O, E> {
+    /// Generates an [`Err`] value of the `err` strategy with the given
+    /// `err_probability` and an [`Ok`] value of the `ok` strategy otherwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `err_probability` is not in the range `[0.0, 1.0]`.
+    pub fn new(ok: ArbStrategy<O>, err: ArbStrategy<E>, err_probability: f64) -> Self {
+        assert_probability(err_probability);
+
+        Self {
+            ok,
+            err,
+            err_probability,
+        }
+    }
+}
+
+impl<O: ArbInterop, E: ArbInterop> Strategy for ArbResultStrategy<O, E> {
+    type Tree = ArbResultValueTree<O, E>;
+    type Value = Result<O, E>;
+
+    fn new_tree(&self, run: &mut TestRunner) -> proptest::strategy::NewTree<Self> {
+        let ok = self.ok.new_tree(run)?;
+        let err = self.err.new_tree(run)?;
+        let is_err = proptest::bool::weighted(self.err_probability)
+            .new_tree(run)?
+            .current();
+
+        Ok(ArbResultValueTree { ok, err, is_err })
+    }
+}
+
+/// The [`proptest::strategy::ValueTree`] of an [`ArbResultStrategy`].
+///
+/// Only the selected variant shrinks: an [`Ok`] value shrinks its payload, and
+/// so does an [`Err`] value. The variant itself never changes.
+#[derive(Debug, Clone)]
+pub struct ArbResultValueTree<O: Debug, E: Debug> {
+    ok: ArbValueTree<O>,
+    err: ArbValueTree<E>,
+    is_err: bool,
+}
+
+impl<O: ArbInterop, E: ArbInterop> ValueTree for ArbResultValueTree<O, E> {
+    type Value = Result<O, E>;
+
+    fn current(&self) -> Self::Value {
+        if self.is_err {
+            Err(self.err.current())
+        } else {
+            Ok(self.ok.current())
+        }
+    }
+
+    fn simplify(&mut self) -> bool {
+        if self.is_err {
+            self.err.simplify()
+        } else {
+            self.ok.simplify()
+        }
+    }
+
+    fn complicate(&mut self) -> bool {
+        if self.is_err {
+            self.err.complicate()
+        } else {
+            self.ok.complicate()
+        }
+    }
+}
+
+/// Constructs an [`ArbResultStrategy`] for given [`arbitrary::Arbitrary`]
+/// types, sized like [`arb`] does, that generates [`Err`] with probability
+/// `err_probability`.
+///
+/// # Panics
+///
+/// Panics if `err_probability` is not in the range `[0.0, 1.0]`.
+pub fn arb_result<O: ArbInterop, E: ArbInterop>(err_probability: f64) -> ArbResultStrategy<O, E> {
+    ArbResultStrategy::new(arb(), arb(), err_probability)
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, feature(coverage_attribute))]
+mod tests {
+    use super::*;
+    use crate::arb_sized;
+
+    fn num_errs(err_probability: f64) -> usize {
+        let strategy = arb_result::<u8, i8>(err_probability);
+        let mut runner = TestRunner::deterministic();
+        (0..1000)
+            .filter(|_| strategy.new_tree(&mut runner).unwrap().current().is_err())
+            .count()
+    }
+
+    fn shrink_completely<O: ArbInterop, E: ArbInterop>(
+        mut tree: ArbResultValueTree<O, E>,
+    ) -> Result<O, E> {
+        while tree.simplify() {}
+        tree.current()
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn err_probability_is_respected() {
+        assert_eq!(0, num_errs(0.0));
+        assert!(num_errs(0.25).abs_diff(250) <= 70);
+        assert_eq!(1000, num_errs(1.0));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn ok_payload_shrinks() {
+        let strategy = ArbResultStrategy::new(arb_sized::<u32>(4), arb_sized::<u32>(4), 0.0);
+        let tree = strategy.new_tree(&mut TestRunner::deterministic()).unwrap();
+
+        assert_eq!(Ok(0), shrink_completely(tree));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn err_payload_shrinks() {
+        let strategy = ArbResultStrategy::new(arb_sized::<u32>(4), arb_sized::<u32>(4), 1.0);
+        let tree = strategy.new_tree(&mut TestRunner::deterministic()).unwrap();
+
+        assert_eq!(Err(0), shrink_completely(tree));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[should_panic]
+    fn probability_must_not_exceed_one() {
+        let _ = arb_result::<u8, u8>(1.5);
+    }
+}
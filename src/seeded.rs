@@ -0,0 +1,125 @@
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use proptest::prelude::RngCore;
+use proptest::strategy::Strategy;
+use proptest::test_runner::RngAlgorithm;
+use proptest::test_runner::TestRng;
+use proptest::test_runner::TestRunner;
+
+use crate::ArbInterop;
+use crate::ArbStrategy;
+use crate::ArbValueTree;
+use crate::arb;
+use crate::lock;
+
+/// A [`proptest::strategy::Strategy`] that draws its random data from its own
+/// seeded random number generator instead of the [`TestRunner`]'s.
+///
+/// Successive test cases use successive outputs of the generator, so the
+/// sequence of generated values only depends on the seed, not on the
+/// configuration of proptest. Useful for deterministic benchmark corpora and
+/// gold-master tests. See [`arb_seed`].
+///
+/// Clones of the strategy share the generator.
+#[derive(Clone, Debug)]
+pub struct SeededArbStrategy<A: ArbInterop> {
+    strategy: ArbStrategy<A>,
+    rng: Arc<Mutex<TestRng>>,
+}
+
+impl<A: ArbInterop> SeededArbStrategy<A> {
+    /// Feeds random data derived from `seed` to the given `strategy`.
+    ///
+    /// Only the random data is independent of the [`TestRunner`]. If the size
+    /// of the random data varies, as with
+    /// [`SizeMode::Range`](crate::SizeMode::Range), sampling it still uses the
+    /// runner.
+    pub fn new(strategy: ArbStrategy<A>, seed: [u8; 32]) -> Self {
+        Self {
+            strategy,
+            rng: Arc::new(Mutex::new(TestRng::from_seed(RngAlgorithm::ChaCha, &seed))),
+        }
+    }
+}
+
+impl<A: ArbInterop> Strategy for SeededArbStrategy<A> {
+    type Tree = ArbValueTree<A>;
+    type Value = A;
+
+    fn new_tree(&self, run: &mut TestRunner) -> proptest::strategy::NewTree<Self> {
+        self.strategy.new_tree_with(run, |_, bytes| {
+            let mut rng = lock(&self.rng);
+            rng.fill_bytes(bytes);
+        })
+    }
+}
+
+/// Constructs a [`SeededArbStrategy`] for a given [`arbitrary::Arbitrary`]
+/// type, sized like [`arb`] does, whose random data is derived from `seed`
+/// alone.
+pub fn arb_seed<A: ArbInterop>(seed: [u8; 32]) -> SeededArbStrategy<A> {
+    SeededArbStrategy::new(arb(), seed)
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, feature(coverage_attribute))]
+mod tests {
+    use proptest::strategy::ValueTree;
+
+    use super::*;
+
+    fn generate(strategy: &SeededArbStrategy<u64>, run: &mut TestRunner) -> Vec<u64> {
+        (0..10)
+            .map(|_| strategy.new_tree(run).unwrap().current())
+            .collect()
+    }
+
+    fn runner_with_seed(seed: u8) -> TestRunner {
+        let rng = TestRng::from_seed(RngAlgorithm::ChaCha, &[seed; 32]);
+        TestRunner::new_with_rng(proptest::test_runner::Config::default(), rng)
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn values_only_depend_on_the_seed() {
+        let values = generate(&arb_seed([7; 32]), &mut runner_with_seed(1));
+
+        assert_eq!(
+            values,
+            generate(&arb_seed([7; 32]), &mut runner_with_seed(2))
+        );
+        assert_ne!(
+            values,
+            generate(&arb_seed([8; 32]), &mut runner_with_seed(1))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn successive_values_differ() {
+        let values = generate(&arb_seed([7; 32]), &mut TestRunner::deterministic());
+
+        assert!(values.windows(2).all(|w| w[0] != w[1]));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn runner_rng_is_not_used() {
+        let mut runner = TestRunner::deterministic();
+        generate(&arb_seed([7; 32]), &mut runner);
+
+        let expected = TestRunner::deterministic().rng().next_u64();
+        assert_eq!(expected, runner.rng().next_u64());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn clones_share_the_generator() {
+        let strategy = arb_seed([7; 32]);
+        let mut runner = TestRunner::deterministic();
+        let first = generate(&strategy.clone(), &mut runner);
+
+        assert_ne!(first, generate(&strategy, &mut runner));
+    }
+}
@@ -0,0 +1,567 @@
+use alloc::sync::Arc;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt::Debug;
+use core::hash::Hash;
+use core::hash::Hasher;
+
+use proptest::strategy::Strategy;
+use proptest::test_runner::TestRunner;
+
+use crate::ArbInterop;
+use crate::ArbStrategy;
+use crate::ArbValueTree;
+use crate::arb;
+
+/// Decides how an [`ArbValueTree`] shrinks its random data, replacing the
+/// built-in shrinking. See [`ArbStrategy::then_shrink_with`] and
+/// [`ArbStrategy::with_shrink_strategy`].
+///
+/// The tree generates a value from whatever random data the strategy
+/// proposes. If the [`arbitrary::Arbitrary`] implementation rejects the data,
+/// the tree immediately [complicates](Self::complicate) it again.
+pub trait ShrinkStrategy: Debug + Send + Sync {
+    /// The state of shrinking before the first simplification of `bytes`.
+    fn initial_state(&self, bytes: &[u8]) -> ShrinkState;
+
+    /// Simplifies `bytes`, returning whether anything changed.
+    fn simplify(&self, state: &mut ShrinkState, bytes: &mut Vec<u8>) -> bool;
+
+    /// Undoes the most recent simplification of `bytes`, since it does not
+    /// reproduce the failure. Returns whether anything changed.
+    fn complicate(&self, state: &mut ShrinkState, bytes: &mut Vec<u8>) -> bool;
+}
+
+/// The state a [`ShrinkStrategy`] keeps for one [`ArbValueTree`].
+#[derive(Clone, PartialEq, Eq, Default, Debug)]
+pub struct ShrinkState {
+    /// Which phase shrinking is in, as the strategy defines them.
+    pub phase: usize,
+
+    /// How far shrinking got, as the strategy defines it.
+    pub position: usize,
+
+    /// The bytes before the most recent simplification, if the strategy keeps
+    /// them to complicate.
+    pub previous: Option<Vec<u8>>,
+}
+
+/// A [`ShrinkStrategy`] that drops the last byte of the random data, one at a
+/// time, until the shorter data no longer reproduces the failure.
+///
+/// Takes as many steps as there are bytes to drop, so it is slower than the
+/// built-in shrinking, but it is simple and predictable.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Debug)]
+pub struct LinearTruncateShrinkStrategy;
+
+impl ShrinkStrategy for LinearTruncateShrinkStrategy {
+    /// The position is the number of bytes known to be needed.
+    fn initial_state(&self, _: &[u8]) -> ShrinkState {
+        ShrinkState::default()
+    }
+
+    fn simplify(&self, state: &mut ShrinkState, bytes: &mut Vec<u8>) -> bool {
+        if bytes.len() <= state.position {
+            return false;
+        }
+
+        state.previous = Some(bytes.clone());
+        bytes.pop();
+
+        true
+    }
+
+    fn complicate(&self, state: &mut ShrinkState, bytes: &mut Vec<u8>) -> bool {
+        let Some(previous) = state.previous.take() else {
+            return false;
+        };
+
+        *bytes = previous;
+        state.position = bytes.len();
+
+        true
+    }
+}
+
+/// A [`ShrinkStrategy`] that searches for the shortest prefix of the random
+/// data reproducing the failure using binary search, like the built-in
+/// shrinking does after zeroing bytes.
+///
+/// Takes a number of steps logarithmic in the number of bytes. See
+/// [`ArbStrategy::with_binary_search_shrink`].
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Debug)]
+pub struct BinarySearchShrinkStrategy;
+
+impl ShrinkStrategy for BinarySearchShrinkStrategy {
+    /// The position is the length of the shortest prefix that might reproduce
+    /// the failure. The current bytes always reproduce it.
+    fn initial_state(&self, _: &[u8]) -> ShrinkState {
+        ShrinkState::default()
+    }
+
+    fn simplify(&self, state: &mut ShrinkState, bytes: &mut Vec<u8>) -> bool {
+        let (low, high) = (state.position, bytes.len());
+        if low >= high {
+            return false;
+        }
+
+        state.previous = Some(bytes.clone());
+        bytes.truncate(low + (high - low) / 2);
+
+        true
+    }
+
+    fn complicate(&self, state: &mut ShrinkState, bytes: &mut Vec<u8>) -> bool {
+        let Some(previous) = state.previous.take() else {
+            return false;
+        };
+
+        // Neither the rejected prefix nor any shorter one reproduces the
+        // failure.
+        state.position = bytes.len() + 1;
+        *bytes = previous;
+
+        true
+    }
+}
+
+/// A [`ShrinkStrategy`] that zeroes the bytes of the random data one at a
+/// time, starting with the last one, and then searches for the shortest prefix
+/// reproducing the failure like [`BinarySearchShrinkStrategy`].
+///
+/// Zeroing keeps the length of the random data, which often simplifies values
+/// more than truncation, for example turning integers to zero.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Debug)]
+pub struct ZeroBytesShrinkStrategy;
+
+impl ZeroBytesShrinkStrategy {
+    /// The phase after zeroing, which is the initial phase 0.
+    const TRUNCATING: usize = 1;
+}
+
+impl ShrinkStrategy for ZeroBytesShrinkStrategy {
+    /// While zeroing, the position is the number of bytes, counted from the
+    /// end, that were considered. The previous bytes are only the original
+    /// value of the most recently zeroed byte.
+    fn initial_state(&self, _: &[u8]) -> ShrinkState {
+        ShrinkState::default()
+    }
+
+    fn simplify(&self, state: &mut ShrinkState, bytes: &mut Vec<u8>) -> bool {
+        if state.phase == Self::TRUNCATING {
+            return BinarySearchShrinkStrategy.simplify(state, bytes);
+        }
+
+        while state.position < bytes.len() {
+            state.position += 1;
+            let index = bytes.len() - state.position;
+            if bytes[index] != 0 {
+                state.previous = Some(vec![bytes[index]]);
+                bytes[index] = 0;
+
+                return true;
+            }
+        }
+
+        *state = ShrinkState {
+            phase: Self::TRUNCATING,
+            ..ShrinkState::default()
+        };
+        BinarySearchShrinkStrategy.simplify(state, bytes)
+    }
+
+    fn complicate(&self, state: &mut ShrinkState, bytes: &mut Vec<u8>) -> bool {
+        if state.phase == Self::TRUNCATING {
+            return BinarySearchShrinkStrategy.complicate(state, bytes);
+        }
+
+        let Some(previous) = state.previous.take() else {
+            return false;
+        };
+
+        let index = bytes.len() - state.position;
+        bytes[index] = previous[0];
+
+        true
+    }
+}
+
+/// The [`ShrinkStrategy`] set with [`ArbStrategy::then_shrink_with`], if any.
+/// Equal to every other, so that strategies compare and hash by their
+/// configuration only.
+#[derive(Clone, Default, Debug)]
+pub(crate) struct SharedShrinker(pub(crate) Option<Arc<dyn ShrinkStrategy>>);
+
+impl PartialEq for SharedShrinker {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for SharedShrinker {}
+
+impl PartialOrd for SharedShrinker {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SharedShrinker {
+    fn cmp(&self, _: &Self) -> Ordering {
+        Ordering::Equal
+    }
+}
+
+impl Hash for SharedShrinker {
+    fn hash<H: Hasher>(&self, _: &mut H) {}
+}
+
+/// A [`proptest::strategy::Strategy`] like [`ArbStrategy`] whose trees shrink
+/// according to a custom [`ShrinkStrategy`]. See
+/// [`ArbStrategy::with_shrink_strategy`].
+#[derive(Clone, Debug)]
+pub struct ShrinkingArbStrategy<A: ArbInterop> {
+    strategy: ArbStrategy<A>,
+    shrinker: Arc<dyn ShrinkStrategy>,
+}
+
+impl<A: ArbInterop> ArbStrategy<A> {
+    /// Makes the trees of this strategy shrink according to `shrinker` instead
+    /// of the built-in shrinking, keeping the type of the strategy.
+    ///
+    /// ```
+    /// # use proptest_arbitrary_adapter::BinarySearchShrinkStrategy;
+    /// # use proptest_arbitrary_adapter::arb;
+    /// let strategy = arb::<Vec<u8>>().then_shrink_with(BinarySearchShrinkStrategy::default());
+    /// ```
+    ///
+    /// Clones of the strategy share `shrinker`. Strategies compare and hash
+    /// equal regardless of their shrink strategies.
+    pub fn then_shrink_with(mut self, shrinker: impl ShrinkStrategy + 'static) -> Self {
+        self.shrinker = SharedShrinker(Some(Arc::new(shrinker)));
+        self
+    }
+
+    /// Turns this strategy into a [`ShrinkingArbStrategy`], whose trees shrink
+    /// according to `shrinker` instead of the built-in shrinking.
+    ///
+    /// ```
+    /// # use proptest_arbitrary_adapter::LinearTruncateShrinkStrategy;
+    /// # use proptest_arbitrary_adapter::arb;
+    /// let strategy = arb::<Vec<u8>>().with_shrink_strategy(LinearTruncateShrinkStrategy);
+    /// ```
+    ///
+    /// The result is a separate type, so that wrapping strategies can replace
+    /// the shrinking of an [`ArbStrategy`] that already has a shrink strategy.
+    pub fn with_shrink_strategy(
+        self,
+        shrinker: impl ShrinkStrategy + 'static,
+    ) -> ShrinkingArbStrategy<A> {
+        ShrinkingArbStrategy {
+            strategy: self,
+            shrinker: Arc::new(shrinker),
+        }
+    }
+
+    /// Like [`Self::with_shrink_strategy`] with a
+    /// [`BinarySearchShrinkStrategy`].
+    pub fn with_binary_search_shrink(self) -> ShrinkingArbStrategy<A> {
+        self.with_shrink_strategy(BinarySearchShrinkStrategy)
+    }
+}
+
+/// Constructs an [`ArbStrategy`] for a given [`arbitrary::Arbitrary`] type,
+/// sized like [`arb`] does, whose trees shrink according to the
+/// [`BinarySearchShrinkStrategy`]. Shorthand for
+/// `arb().then_shrink_with(BinarySearchShrinkStrategy)`.
+pub fn arb_binary_search<A: ArbInterop>() -> ArbStrategy<A> {
+    arb().then_shrink_with(BinarySearchShrinkStrategy)
+}
+
+impl<A: ArbInterop> ArbValueTree<A> {
+    /// Makes the tree shrink according to `shrinker`, starting from the
+    /// current value.
+    pub(crate) fn set_shrink_strategy(&mut self, shrinker: Arc<dyn ShrinkStrategy>) {
+        self.bytes.truncate(self.next);
+        let state = shrinker.initial_state(&self.bytes);
+        self.shrinker = Some((shrinker, state));
+    }
+}
+
+impl<A: ArbInterop> Strategy for ShrinkingArbStrategy<A> {
+    type Tree = ArbValueTree<A>;
+    type Value = A;
+
+    fn new_tree(&self, run: &mut TestRunner) -> proptest::strategy::NewTree<Self> {
+        let mut tree = self.strategy.new_tree(run)?;
+        tree.set_shrink_strategy(Arc::clone(&self.shrinker));
+
+        Ok(tree)
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, feature(coverage_attribute))]
+mod tests {
+    use arbitrary::Arbitrary;
+    use proptest::strategy::ValueTree;
+
+    use super::*;
+    use crate::arb_sized;
+    use crate::test_util::Bytes;
+
+    /// Requires at least 8 bytes to be generated from.
+    #[derive(Debug, Clone)]
+    struct AtLeast8;
+
+    impl<'a> Arbitrary<'a> for AtLeast8 {
+        fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+            u.bytes(8)?;
+            Ok(Self)
+        }
+    }
+
+    /// Zeroes all bytes at once, if that reproduces the failure.
+    #[derive(Debug)]
+    struct ZeroAll;
+
+    impl ShrinkStrategy for ZeroAll {
+        fn initial_state(&self, _: &[u8]) -> ShrinkState {
+            ShrinkState::default()
+        }
+
+        fn simplify(&self, state: &mut ShrinkState, bytes: &mut Vec<u8>) -> bool {
+            let is_zero = bytes.iter().all(|&byte| byte == 0);
+            if is_zero || state.position > 0 {
+                return false;
+            }
+
+            state.previous = Some(core::mem::replace(bytes, vec![0; bytes.len()]));
+            true
+        }
+
+        fn complicate(&self, state: &mut ShrinkState, bytes: &mut Vec<u8>) -> bool {
+            state.position = 1;
+            state
+                .previous
+                .take()
+                .map(|previous| *bytes = previous)
+                .is_some()
+        }
+    }
+
+    fn tree<A: ArbInterop>(
+        size: usize,
+        shrinker: impl ShrinkStrategy + 'static,
+    ) -> ArbValueTree<A> {
+        arb_sized::<A>(size)
+            .with_shrink_strategy(shrinker)
+            .new_tree(&mut TestRunner::deterministic())
+            .unwrap()
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn custom_strategy_replaces_builtin_shrinking() {
+        let mut tree = tree::<Bytes>(4, ZeroAll);
+        let original = tree.current();
+
+        assert!(tree.simplify());
+        assert_eq!(Bytes(vec![0; 4]), tree.current());
+        assert!(!tree.simplify());
+
+        assert!(tree.complicate());
+        assert_eq!(original, tree.current());
+        assert!(!tree.simplify());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn linear_truncation_drops_one_byte_at_a_time() {
+        let mut tree = tree::<Bytes>(4, LinearTruncateShrinkStrategy);
+        let original = tree.current().0;
+
+        for len in (0..4).rev() {
+            assert!(tree.simplify());
+            assert_eq!(original[..len], tree.current().0);
+            assert_eq!(len, tree.current_size());
+        }
+        assert!(!tree.simplify());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn clones_shrink_independently() {
+        let tree = tree::<Bytes>(4, LinearTruncateShrinkStrategy);
+        let mut branch_a = tree.clone();
+        let mut branch_b = tree.clone();
+
+        assert!(branch_a.simplify());
+        assert!(branch_a.simplify());
+        assert!(branch_b.simplify());
+        assert!(branch_b.complicate());
+
+        assert_eq!(2, branch_a.current_size());
+        assert_eq!(4, branch_b.current_size());
+        assert!(!branch_b.simplify());
+        assert!(branch_a.simplify());
+        assert_eq!(4, tree.current_size());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn linear_truncation_stops_at_needed_bytes() {
+        let mut tree = tree::<Bytes>(4, LinearTruncateShrinkStrategy);
+        let original = tree.current().0;
+        assert!(tree.simplify());
+        assert!(tree.complicate());
+
+        assert_eq!(original, tree.current().0);
+        assert!(!tree.simplify());
+    }
+
+    /// Shrinks a tree whose values fail if they are at least `min_len` bytes
+    /// long. Returns the final length and the number of steps taken.
+    fn shrink_failing_from(mut tree: ArbValueTree<Bytes>, min_len: usize) -> (usize, usize) {
+        let mut steps = 0;
+        while tree.simplify() {
+            steps += 1;
+            if tree.current().0.len() < min_len {
+                tree.complicate();
+            }
+        }
+
+        (tree.current().0.len(), steps)
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn binary_search_converges_faster_than_linear_truncation() {
+        let binary = arb_sized::<Bytes>(1024)
+            .with_binary_search_shrink()
+            .new_tree(&mut TestRunner::deterministic())
+            .unwrap();
+        let (binary_len, binary_steps) = shrink_failing_from(binary, 700);
+
+        let linear = tree::<Bytes>(1024, LinearTruncateShrinkStrategy);
+        let (linear_len, linear_steps) = shrink_failing_from(linear, 700);
+
+        assert_eq!(700, binary_len);
+        assert_eq!(700, linear_len);
+        assert!(binary_steps <= 11);
+        assert_eq!(325, linear_steps);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn binary_search_skips_rejected_prefixes() {
+        let mut tree = tree::<AtLeast8>(1024, BinarySearchShrinkStrategy);
+        while tree.simplify() {}
+
+        assert_eq!(8, tree.current_size());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn binary_search_has_a_shorthand() {
+        let mut tree = arb_binary_search::<AtLeast8>()
+            .new_tree(&mut TestRunner::deterministic())
+            .unwrap();
+        while tree.simplify() {}
+
+        assert_eq!(8, tree.current_size());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn shrink_strategy_can_be_set_per_instance() {
+        let strategy: ArbStrategy<Bytes> =
+            arb_sized(1024).then_shrink_with(BinarySearchShrinkStrategy);
+        let tree = strategy.new_tree(&mut TestRunner::deterministic()).unwrap();
+        let (len, steps) = shrink_failing_from(tree, 700);
+
+        assert_eq!(700, len);
+        assert!(steps <= 11);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn shrink_strategies_are_left_out_of_comparison() {
+        let binary = arb_sized::<u8>(4).then_shrink_with(BinarySearchShrinkStrategy);
+        let linear = arb_sized::<u8>(4).then_shrink_with(LinearTruncateShrinkStrategy);
+
+        assert_eq!(arb_sized::<u8>(4), binary);
+        assert_eq!(binary, linear);
+        assert_ne!(arb_sized::<u8>(8), binary);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn clones_share_shrink_strategy() {
+        let strategy = arb_sized::<Bytes>(4).then_shrink_with(ZeroAll);
+        let mut tree = strategy
+            .clone()
+            .new_tree(&mut TestRunner::deterministic())
+            .unwrap();
+
+        assert!(tree.simplify());
+        assert_eq!(Bytes(vec![0; 4]), tree.current());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn bytes_are_zeroed_from_the_end() {
+        let mut tree = ArbValueTree::<Bytes>::new(vec![1, 0, 2, 3]).unwrap();
+        tree.set_shrink_strategy(Arc::new(ZeroBytesShrinkStrategy));
+
+        assert!(tree.simplify());
+        assert_eq!(Bytes(vec![1, 0, 2, 0]), tree.current());
+        assert!(tree.simplify());
+        assert_eq!(Bytes(vec![1, 0, 0, 0]), tree.current());
+
+        assert!(tree.complicate());
+        assert_eq!(Bytes(vec![1, 0, 2, 0]), tree.current());
+        assert!(tree.simplify());
+        assert_eq!(Bytes(vec![0, 0, 2, 0]), tree.current());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn zeroing_falls_back_to_truncation() {
+        let mut tree = ArbValueTree::<Bytes>::new(vec![1, 2, 3, 4]).unwrap();
+        tree.set_shrink_strategy(Arc::new(ZeroBytesShrinkStrategy));
+        for _ in 0..4 {
+            assert!(tree.simplify());
+        }
+        assert_eq!(Bytes(vec![0; 4]), tree.current());
+
+        assert!(tree.simplify());
+        assert_eq!(Bytes(vec![0; 2]), tree.current());
+        while tree.simplify() {}
+        assert_eq!(Bytes(vec![]), tree.current());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn zeroing_simplifies_integers_without_truncation() {
+        let mut tree = tree::<u32>(4, ZeroBytesShrinkStrategy);
+        while tree.current() != 0 {
+            assert!(tree.simplify());
+        }
+
+        assert_eq!(4, tree.current_size());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn rejected_bytes_are_skipped() {
+        let mut tree = tree::<AtLeast8>(12, LinearTruncateShrinkStrategy);
+        for _ in 0..4 {
+            assert!(tree.simplify());
+        }
+
+        assert!(!tree.simplify());
+        assert_eq!(8, tree.current_size());
+    }
+}
@@ -0,0 +1,106 @@
+use proptest::prelude::RngCore;
+use proptest::strategy::Strategy;
+use proptest::test_runner::TestRunner;
+
+use crate::ArbInterop;
+use crate::ArbStrategy;
+use crate::ArbValueTree;
+use crate::random_below;
+
+/// A [`proptest::strategy::Strategy`] like [`ArbStrategy`] that shuffles the
+/// random data before feeding it to the [`arbitrary::Arbitrary`]
+/// implementation. See [`ArbStrategy::shuffled`].
+///
+/// The shuffle keeps the bytes but not their order. Since the random data is
+/// uniformly distributed to begin with, so are the shuffled bytes: the values
+/// differ from those of the unshuffled strategy, but follow the same
+/// distribution. Shrinking operates on the shuffled bytes.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct ShuffledArbStrategy<A: ArbInterop> {
+    strategy: ArbStrategy<A>,
+}
+
+impl<A: ArbInterop> ArbStrategy<A> {
+    /// Turns this strategy into a [`ShuffledArbStrategy`], which randomly
+    /// permutes the random data of each test case.
+    pub fn shuffled(self) -> ShuffledArbStrategy<A> {
+        ShuffledArbStrategy { strategy: self }
+    }
+}
+
+impl<A: ArbInterop> Strategy for ShuffledArbStrategy<A> {
+    type Tree = ArbValueTree<A>;
+    type Value = A;
+
+    fn new_tree(&self, run: &mut TestRunner) -> proptest::strategy::NewTree<Self> {
+        self.strategy.new_tree_with(run, |run, bytes| {
+            run.rng().fill_bytes(bytes);
+
+            // Fisher-Yates
+            for i in (1..bytes.len()).rev() {
+                bytes.swap(i, random_below(run, i + 1));
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, feature(coverage_attribute))]
+mod tests {
+    use alloc::vec::Vec;
+
+    use proptest::strategy::ValueTree;
+
+    use super::*;
+    use crate::arb_sized;
+
+    fn sorted(bytes: &[u8]) -> Vec<u8> {
+        let mut bytes = bytes.to_vec();
+        bytes.sort_unstable();
+        bytes
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn random_data_is_permuted() {
+        let shuffled = arb_sized::<u64>(64)
+            .shuffled()
+            .new_tree(&mut TestRunner::deterministic())
+            .unwrap();
+        let unshuffled = arb_sized::<u64>(64)
+            .new_tree(&mut TestRunner::deterministic())
+            .unwrap();
+
+        assert_ne!(unshuffled.raw_bytes(), shuffled.raw_bytes());
+        assert_eq!(sorted(unshuffled.raw_bytes()), sorted(shuffled.raw_bytes()));
+        assert_ne!(unshuffled.current(), shuffled.current());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn values_differ_from_unshuffled_ones() {
+        let mut runner = TestRunner::deterministic();
+        let mut other_runner = TestRunner::deterministic();
+        let num_equal = (0..100)
+            .filter(|_| {
+                let shuffled = arb_sized::<u32>(4).shuffled().new_tree(&mut runner);
+                let unshuffled = arb_sized::<u32>(4).new_tree(&mut other_runner);
+                shuffled.unwrap().current() == unshuffled.unwrap().current()
+            })
+            .count();
+
+        assert!(num_equal < 10);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn shuffled_values_shrink() {
+        let mut tree = arb_sized::<u64>(8)
+            .shuffled()
+            .new_tree(&mut TestRunner::deterministic())
+            .unwrap();
+        while tree.simplify() {}
+
+        assert_eq!(0, tree.current());
+    }
+}
@@ -0,0 +1,117 @@
+use proptest::strategy::Strategy;
+use proptest::test_runner::TestRunner;
+
+use crate::ArbInterop;
+use crate::ArbStrategy;
+
+/// How many bytes of random data an [`arbitrary::Arbitrary`] implementation
+/// actually reads, among the values of a sample. See
+/// [`ArbStrategy::size_distribution_test`].
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub struct SizeStats {
+    /// The fewest bytes read for any value.
+    pub min_consumed: usize,
+
+    /// The most bytes read for any value.
+    pub max_consumed: usize,
+
+    /// The mean number of bytes read.
+    pub mean_consumed: f64,
+
+    /// The standard deviation of the number of bytes read.
+    pub std_dev_consumed: f64,
+}
+
+impl<A: ArbInterop> ArbStrategy<A> {
+    /// Generates `n` values and reports how many bytes of random data the
+    /// [`arbitrary::Arbitrary`] implementation read for them, or all zeros if
+    /// `n` is zero.
+    ///
+    /// A diagnostic for tuning [`size_hint`](arbitrary::Arbitrary::size_hint)s
+    /// and sizes: if even the [`max_consumed`](SizeStats::max_consumed) bytes
+    /// are far below the [`buffer_size`](Self::buffer_size), most random data
+    /// is wasted. The random data is deterministic, so repeated tests report
+    /// the same statistics.
+    ///
+    /// Printing the statistics in an ignored test lets you inspect them on
+    /// demand:
+    ///
+    /// ```
+    /// # use proptest_arbitrary_adapter::arb;
+    /// let stats = arb::<Vec<u8>>().size_distribution_test(1000);
+    /// println!("{stats:?}");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if generating a value fails, for example because the
+    /// [`arbitrary::Arbitrary`] implementation rejects too much random data.
+    pub fn size_distribution_test(&self, n: usize) -> SizeStats {
+        if n == 0 {
+            return SizeStats::default();
+        }
+
+        let mut run = TestRunner::deterministic();
+        let mut stats = SizeStats {
+            min_consumed: usize::MAX,
+            ..SizeStats::default()
+        };
+        let mut sum = 0.0;
+        let mut sum_of_squares = 0.0;
+        for _ in 0..n {
+            let tree = self
+                .new_tree(&mut run)
+                .unwrap_or_else(|reason| panic!("failed to generate value: {reason}"));
+            let consumed = tree.bytes_consumed();
+            stats.min_consumed = stats.min_consumed.min(consumed);
+            stats.max_consumed = stats.max_consumed.max(consumed);
+            sum += consumed as f64;
+            sum_of_squares += (consumed as f64).powi(2);
+        }
+        stats.mean_consumed = sum / n as f64;
+        let variance = sum_of_squares / n as f64 - stats.mean_consumed.powi(2);
+        stats.std_dev_consumed = variance.max(0.0).sqrt();
+
+        stats
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, feature(coverage_attribute))]
+mod tests {
+    use super::*;
+    use crate::arb;
+    use crate::arb_sized;
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn fixed_consumption_has_no_deviation() {
+        let stats = arb::<u32>().size_distribution_test(100);
+
+        let expected = SizeStats {
+            min_consumed: 4,
+            max_consumed: 4,
+            mean_consumed: 4.0,
+            std_dev_consumed: 0.0,
+        };
+        assert_eq!(expected, stats);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn varying_consumption_is_summarized() {
+        let stats = arb_sized::<Vec<u8>>(64).size_distribution_test(1000);
+
+        assert!(stats.min_consumed < stats.max_consumed);
+        assert!(stats.max_consumed <= 64);
+        assert!(stats.min_consumed as f64 <= stats.mean_consumed);
+        assert!(stats.mean_consumed <= stats.max_consumed as f64);
+        assert!(stats.std_dev_consumed > 0.0);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn empty_samples_report_zeros() {
+        assert_eq!(SizeStats::default(), arb::<u32>().size_distribution_test(0));
+    }
+}
@@ -0,0 +1,118 @@
+use alloc::sync::Arc;
+use core::sync::atomic::AtomicU64;
+use core::sync::atomic::Ordering;
+
+use proptest::prelude::RngCore;
+use proptest::strategy::Strategy;
+use proptest::test_runner::TestRunner;
+
+use crate::ArbInterop;
+use crate::ArbStrategy;
+use crate::ArbValueTree;
+
+/// A [`proptest::strategy::Strategy`] like [`ArbStrategy`] that counts how
+/// often the [`arbitrary::Arbitrary`] implementation rejected random data.
+///
+/// A high [rejection count](Self::rejection_count) hints at a misconfigured
+/// size, for example a buffer too small for the type. See
+/// [`ArbStrategy::with_stats`].
+///
+/// Clones of the strategy share the count.
+#[derive(Clone, Debug)]
+pub struct StatsArbStrategy<A: ArbInterop> {
+    strategy: ArbStrategy<A>,
+    rejections: Arc<AtomicU64>,
+}
+
+impl<A: ArbInterop> StatsArbStrategy<A> {
+    /// The total number of times random data was rejected so far.
+    pub fn rejection_count(&self) -> u64 {
+        self.rejections.load(Ordering::Relaxed)
+    }
+}
+
+impl<A: ArbInterop> ArbStrategy<A> {
+    /// Turns this strategy into a [`StatsArbStrategy`], which counts rejections
+    /// of random data.
+    pub fn with_stats(self) -> StatsArbStrategy<A> {
+        StatsArbStrategy {
+            strategy: self,
+            rejections: Arc::default(),
+        }
+    }
+}
+
+impl<A: ArbInterop> Strategy for StatsArbStrategy<A> {
+    type Tree = ArbValueTree<A>;
+    type Value = A;
+
+    fn new_tree(&self, run: &mut TestRunner) -> proptest::strategy::NewTree<Self> {
+        self.strategy.new_tree_observed(
+            run,
+            |run, bytes| run.rng().fill_bytes(bytes),
+            || {
+                self.rejections.fetch_add(1, Ordering::Relaxed);
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, feature(coverage_attribute))]
+mod tests {
+    use arbitrary::Arbitrary;
+
+    use super::*;
+    use crate::arb;
+    use crate::arb_sized;
+
+    /// Rejects all random data with an even first byte.
+    #[derive(Debug, Clone)]
+    struct Odd;
+
+    impl<'a> Arbitrary<'a> for Odd {
+        fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+            let byte = u8::arbitrary(u)?;
+            if byte % 2 == 0 {
+                return Err(arbitrary::Error::IncorrectFormat);
+            }
+
+            Ok(Self)
+        }
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn rejections_are_counted() {
+        let strategy = arb::<Odd>().with_stats();
+        let mut runner = TestRunner::deterministic();
+        for _ in 0..100 {
+            strategy.new_tree(&mut runner).unwrap();
+        }
+
+        assert!(strategy.rejection_count() > 0);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn acceptable_data_is_not_counted() {
+        let strategy = arb::<u8>().with_stats();
+        let mut runner = TestRunner::deterministic();
+        for _ in 0..100 {
+            strategy.new_tree(&mut runner).unwrap();
+        }
+
+        assert_eq!(0, strategy.rejection_count());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn clones_share_the_count() {
+        // Without any random data, the first byte is always zero.
+        let strategy = arb_sized::<Odd>(0).with_stats();
+        let clone = strategy.clone();
+        let _ = clone.new_tree(&mut TestRunner::deterministic());
+
+        assert_eq!(1000, strategy.rejection_count());
+    }
+}
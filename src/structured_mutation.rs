@@ -0,0 +1,239 @@
+use core::ops::Range;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use proptest::prelude::RngCore;
+use proptest::strategy::Strategy;
+use proptest::strategy::ValueTree;
+use proptest::test_runner::TestRunner;
+
+use crate::ArbInterop;
+use crate::ArbStrategy;
+use crate::ArbValueTree;
+use crate::arb;
+use crate::assert_probability;
+use crate::lock;
+use crate::random_below;
+
+/// A [`proptest::strategy::Strategy`] that generates new test cases by
+/// mutating a single field of the previous value at a time, for more
+/// meaningful mutations of nested types than [`MutatingArbStrategy`] makes.
+///
+/// The strategy remembers the random data of the last generated value. For
+/// every test case, with some probability, the random data is that of the last
+/// value with the bytes of one randomly picked field mutated, instead of fresh
+/// random data. Mutations are bit flips and byte replacements, which keep the
+/// field boundaries in place. See [`arb_structured_mutation`].
+///
+/// Clones of the strategy share the last random data.
+///
+/// [`MutatingArbStrategy`]: crate::MutatingArbStrategy
+#[derive(Clone, Debug)]
+pub struct StructuredMutationArbStrategy<A: ArbInterop> {
+    strategy: ArbStrategy<A>,
+    field_size_hints: Vec<(usize, Option<usize>)>,
+    mutation_probability: f64,
+    last_bytes: Arc<Mutex<Option<Vec<u8>>>>,
+}
+
+impl<A: ArbInterop> StructuredMutationArbStrategy<A> {
+    /// Mutates the random data of the given `strategy` field by field.
+    ///
+    /// [`arbitrary::Arbitrary::size_hint`] of `A` only covers all fields
+    /// together, so `field_size_hints` lists the size hints of the fields in
+    /// the order `A` reads them, typically `<Field as Arbitrary>::size_hint(1)`
+    /// per field. A field spans the upper bound of its hint. A field without
+    /// an upper bound spans the rest of the random data, so only the last
+    /// field may lack one.
+    ///
+    /// The mutations are only as structured as `A` reads its random data in
+    /// that order and in those sizes. Derived implementations for structs of
+    /// fixed-size fields, like integers and arrays of integers, do. For fields
+    /// of varying size, the boundaries are estimates.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mutation_probability` is not in the range `[0.0, 1.0]`.
+    pub fn new(
+        strategy: ArbStrategy<A>,
+        field_size_hints: Vec<(usize, Option<usize>)>,
+        mutation_probability: f64,
+    ) -> Self {
+        assert_probability(mutation_probability);
+
+        Self {
+            strategy,
+            field_size_hints,
+            mutation_probability,
+            last_bytes: Arc::default(),
+        }
+    }
+
+    /// The non-empty byte ranges of the fields within random data of length
+    /// `len`.
+    fn field_ranges(&self, len: usize) -> Vec<Range<usize>> {
+        let mut ranges = Vec::with_capacity(self.field_size_hints.len());
+        let mut start = 0_usize;
+        for &(_, upper) in &self.field_size_hints {
+            let end = upper.map_or(len, |size| start.saturating_add(size).min(len));
+            if start < end {
+                ranges.push(start..end);
+            }
+            start = end;
+        }
+
+        ranges
+    }
+
+    /// Fills the given buffer with either the last random data with one field
+    /// mutated, or fresh random data.
+    fn fill_bytes(&self, run: &mut TestRunner, bytes: &mut [u8]) {
+        run.rng().fill_bytes(bytes);
+
+        let last_bytes = lock(&self.last_bytes);
+        let Some(last_bytes) = last_bytes.as_ref() else {
+            return;
+        };
+
+        let is_mutation_due = proptest::bool::weighted(self.mutation_probability)
+            .new_tree(run)
+            .is_ok_and(|tree| tree.current());
+        let ranges = self.field_ranges(bytes.len());
+        if !is_mutation_due || ranges.is_empty() {
+            return;
+        }
+
+        // Sizes may vary per test case, so the last random data may be shorter
+        // or longer. Fresh random data makes up for missing bytes.
+        let len = last_bytes.len().min(bytes.len());
+        bytes[..len].copy_from_slice(&last_bytes[..len]);
+
+        let field = ranges[random_below(run, ranges.len())].clone();
+        mutate_field(run, &mut bytes[field]);
+    }
+}
+
+/// Applies one random mutation to the given non-empty field.
+fn mutate_field(run: &mut TestRunner, field: &mut [u8]) {
+    let index = random_below(run, field.len());
+    if run.rng().next_u32().is_multiple_of(2) {
+        field[index] ^= 1 << random_below(run, 8);
+    } else {
+        field[index] = run.rng().next_u32() as u8;
+    }
+}
+
+impl<A: ArbInterop> Strategy for StructuredMutationArbStrategy<A> {
+    type Tree = ArbValueTree<A>;
+    type Value = A;
+
+    fn new_tree(&self, run: &mut TestRunner) -> proptest::strategy::NewTree<Self> {
+        let tree = self
+            .strategy
+            .new_tree_with(run, |run, bytes| self.fill_bytes(run, bytes))?;
+        let mut last_bytes = lock(&self.last_bytes);
+        *last_bytes = Some(tree.raw_bytes().to_vec());
+
+        Ok(tree)
+    }
+}
+
+/// Constructs a [`StructuredMutationArbStrategy`] for a given
+/// [`arbitrary::Arbitrary`] type, sized like [`arb`] does.
+///
+/// `field_size_hints` lists the size hints of the fields of `A` in the order
+/// `A` reads them, see [`StructuredMutationArbStrategy::new`]. For every test
+/// case, one field of the previous value is mutated with probability
+/// `mutation_probability`.
+///
+/// ```
+/// use arbitrary::Arbitrary;
+/// use proptest::strategy::Strategy;
+/// use proptest::strategy::ValueTree;
+/// use proptest::test_runner::TestRunner;
+/// use proptest_arbitrary_adapter::arb_structured_mutation;
+///
+/// #[derive(Arbitrary, Clone, Debug)]
+/// struct Header {
+///     version: u8,
+///     length: u32,
+/// }
+///
+/// let fields = vec![u8::size_hint(1), u32::size_hint(1)];
+/// let strategy = arb_structured_mutation::<Header>(fields, 0.9);
+///
+/// let mut runner = TestRunner::deterministic();
+/// let header = strategy.new_tree(&mut runner).unwrap().current();
+/// ```
+///
+/// # Panics
+///
+/// Panics if `mutation_probability` is not in the range `[0.0, 1.0]`.
+pub fn arb_structured_mutation<A: ArbInterop>(
+    field_size_hints: Vec<(usize, Option<usize>)>,
+    mutation_probability: f64,
+) -> StructuredMutationArbStrategy<A> {
+    StructuredMutationArbStrategy::new(arb(), field_size_hints, mutation_probability)
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, feature(coverage_attribute))]
+mod tests {
+    use arbitrary::Arbitrary;
+
+    use super::*;
+
+    type Pair = ([u8; 4], [u8; 4]);
+
+    fn pair_fields() -> Vec<(usize, Option<usize>)> {
+        vec![<[u8; 4]>::size_hint(1), <[u8; 4]>::size_hint(1)]
+    }
+
+    fn generate_pairs(strategy: &StructuredMutationArbStrategy<Pair>) -> Vec<Pair> {
+        let mut runner = TestRunner::deterministic();
+        (0..100)
+            .map(|_| strategy.new_tree(&mut runner).unwrap().current())
+            .collect()
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn mutations_change_one_field_at_a_time() {
+        let strategy = arb_structured_mutation::<Pair>(pair_fields(), 1.0);
+        let pairs = generate_pairs(&strategy);
+
+        for pair in pairs.windows(2) {
+            let num_changed_fields =
+                usize::from(pair[0].0 != pair[1].0) + usize::from(pair[0].1 != pair[1].1);
+            assert!(num_changed_fields <= 1);
+        }
+        assert!(pairs.iter().any(|pair| pair.0 != pairs[0].0));
+        assert!(pairs.iter().any(|pair| pair.1 != pairs[0].1));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn no_mutations_give_fresh_bytes() {
+        let strategy = arb_structured_mutation::<Pair>(pair_fields(), 0.0);
+        let pairs = generate_pairs(&strategy);
+
+        assert!(pairs.windows(2).all(|pair| pair[0].0 != pair[1].0));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn fields_are_clipped_to_random_data() {
+        let strategy = arb_structured_mutation::<Pair>(vec![(2, Some(2)), (0, None)], 1.0);
+
+        assert_eq!(vec![0..2, 2..10], strategy.field_ranges(10));
+        assert_eq!(vec![0..1], strategy.field_ranges(1));
+        assert!(strategy.field_ranges(0).is_empty());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[should_panic]
+    fn probability_must_not_exceed_one() {
+        let _ = arb_structured_mutation::<Pair>(pair_fields(), 1.5);
+    }
+}
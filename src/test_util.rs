@@ -0,0 +1,23 @@
+use alloc::vec::Vec;
+
+use arbitrary::Arbitrary;
+
+/// Records the number of bytes it was generated from.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Len(pub(crate) usize);
+
+impl<'a> Arbitrary<'a> for Len {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self(u.len()))
+    }
+}
+
+/// Records the bytes it was generated from.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Bytes(pub(crate) Vec<u8>);
+
+impl<'a> Arbitrary<'a> for Bytes {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self(u.bytes(u.len())?.to_vec()))
+    }
+}
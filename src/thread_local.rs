@@ -0,0 +1,164 @@
+use std::cell::RefCell;
+
+use proptest::prelude::RngCore;
+use proptest::strategy::Strategy;
+use proptest::test_runner::TestRunner;
+
+use crate::ArbInterop;
+use crate::ArbStrategy;
+use crate::ArbValueTree;
+use crate::arb;
+
+std::thread_local! {
+    /// The random data of the test case currently being generated.
+    static BUFFER: RefCell<Vec<u8>> = const { RefCell::new(Vec::new()) };
+}
+
+/// A [`proptest::strategy::Strategy`] like [`ArbStrategy`] that fills a
+/// buffer of the current thread with random data instead of allocating a new
+/// one for every attempt to generate a value.
+///
+/// Only random data the [`arbitrary::Arbitrary`] implementation accepts is
+/// copied into the [`ArbValueTree`]. Filling the buffer with random data costs
+/// far more than allocating it, though, so the benchmark `thread_local` only
+/// shows a speedup of about 5%, for a type that rejects most random data. See
+/// [`arb_thread_local`].
+///
+/// The values are the same as those of the wrapped strategy, except with the
+/// feature `rayon`, which this strategy does not use to search for acceptable
+/// random data.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct ThreadLocalArbStrategy<A: ArbInterop> {
+    strategy: ArbStrategy<A>,
+}
+
+impl<A: ArbInterop> ThreadLocalArbStrategy<A> {
+    /// Generates values like the given `strategy`, reusing the buffer of the
+    /// current thread.
+    pub fn new(strategy: ArbStrategy<A>) -> Self {
+        Self { strategy }
+    }
+}
+
+impl<A: ArbInterop> Strategy for ThreadLocalArbStrategy<A> {
+    type Tree = ArbValueTree<A>;
+    type Value = A;
+
+    fn new_tree(&self, run: &mut TestRunner) -> proptest::strategy::NewTree<Self> {
+        let strategy = &self.strategy;
+        BUFFER.with_borrow_mut(|buffer| {
+            let mut num_retries = 0;
+            loop {
+                buffer.clear();
+                buffer.resize(strategy.config.size_mode.sample(run)?, 0);
+                run.rng().fill_bytes(buffer);
+
+                match ArbValueTree::<A>::gen_one_with_size(buffer, buffer.len()) {
+                    Ok(curr) => {
+                        let mut tree = ArbValueTree::with_value(buffer.clone(), curr);
+                        strategy.configure_tree(&mut tree);
+
+                        return Ok(tree);
+                    }
+                    Err(
+                        e @ (arbitrary::Error::IncorrectFormat | arbitrary::Error::NotEnoughData),
+                    ) => strategy.reject(run, e, &mut num_retries, &mut || ())?,
+                    Err(e) => return Err(format!("{}: {e}", strategy.origin()).into()),
+                }
+            }
+        })
+    }
+}
+
+/// Constructs a [`ThreadLocalArbStrategy`] for a given [`arbitrary::Arbitrary`]
+/// type, sized like [`arb`] does.
+pub fn arb_thread_local<A: ArbInterop>() -> ThreadLocalArbStrategy<A> {
+    ThreadLocalArbStrategy::new(arb())
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, feature(coverage_attribute))]
+mod tests {
+    use arbitrary::Arbitrary;
+    use proptest::strategy::ValueTree;
+
+    use super::*;
+    use crate::ArbConfig;
+    use crate::arb_sized;
+
+    /// Rejects all random data with an even first byte.
+    #[derive(Debug, Clone)]
+    struct Odd;
+
+    impl<'a> Arbitrary<'a> for Odd {
+        fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+            if u8::arbitrary(u)? % 2 == 0 {
+                return Err(arbitrary::Error::IncorrectFormat);
+            }
+
+            Ok(Self)
+        }
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn values_match_wrapped_strategy() {
+        let mut runner = TestRunner::deterministic();
+        let mut other_runner = TestRunner::deterministic();
+        for _ in 0..10 {
+            let tree = arb_thread_local::<Vec<u16>>()
+                .new_tree(&mut runner)
+                .unwrap();
+            let expected = arb::<Vec<u16>>().new_tree(&mut other_runner).unwrap();
+            assert_eq!(expected, tree);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn rejected_data_is_not_copied() {
+        let strategy = ThreadLocalArbStrategy::<Odd>::new(arb_sized(16));
+        let mut runner = TestRunner::deterministic();
+        for _ in 0..10 {
+            let tree = strategy.new_tree(&mut runner).unwrap();
+            assert_eq!(1, tree.raw_bytes()[0] % 2);
+            assert_eq!(16, tree.raw_bytes().len());
+        }
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn buffer_is_resized_to_each_sample() {
+        let strategy = ThreadLocalArbStrategy::<u8>::new(ArbStrategy::with_size_range(1, 64));
+        let mut runner = TestRunner::deterministic();
+        let sizes = (0..100)
+            .map(|_| strategy.new_tree(&mut runner).unwrap().raw_bytes().len())
+            .collect::<Vec<_>>();
+
+        assert!(sizes.iter().all(|size| (1..=64).contains(size)));
+        assert!(sizes.windows(2).any(|w| w[0] > w[1]));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn shrinking_respects_configuration() {
+        let config = ArbConfig::builder().size(8).shrink_max_steps(1).build();
+        let strategy = ThreadLocalArbStrategy::<u64>::new(ArbStrategy::with_config(config));
+        let mut tree = strategy.new_tree(&mut TestRunner::deterministic()).unwrap();
+
+        assert!(tree.simplify());
+        assert!(!tree.simplify());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn too_many_rejections_fail() {
+        let config = ArbConfig::builder().size(0).max_retries(3).build();
+        let strategy = ThreadLocalArbStrategy::<Odd>::new(ArbStrategy::with_config(config));
+        let reason = strategy
+            .new_tree(&mut TestRunner::deterministic())
+            .unwrap_err();
+
+        assert!(reason.message().contains("too many rejections"));
+    }
+}
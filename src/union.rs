@@ -0,0 +1,178 @@
+use alloc::boxed::Box;
+use core::fmt::Debug;
+
+use proptest::strategy::Strategy;
+use proptest::strategy::ValueTree;
+use proptest::test_runner::TestRunner;
+
+use crate::ArbInterop;
+use crate::ArbStrategy;
+use crate::ArbValueTree;
+
+/// A [`proptest::strategy::Strategy`] that generates each value either with an
+/// [`ArbStrategy`] or with any other strategy of the same value type, picked at
+/// random by weight. See [`ArbStrategy::prop_union`].
+///
+/// Unlike [`proptest::prop_oneof!`], the type of the other strategy is kept,
+/// so there is no dynamic dispatch. A value shrinks only within the strategy
+/// that generated it.
+#[derive(Clone, Debug)]
+pub struct UnionArbStrategy<A: ArbInterop, S> {
+    strategy: ArbStrategy<A>,
+    other: S,
+    weight: u32,
+    other_weight: u32,
+}
+
+impl<A: ArbInterop, S: Strategy<Value = A>> UnionArbStrategy<A, S> {
+    /// Picks `strategy` with a probability of `weight` divided by the sum of
+    /// both weights, and `other` otherwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if both weights are zero.
+    pub fn new(strategy: ArbStrategy<A>, other: S, weight: u32, other_weight: u32) -> Self {
+        assert!(
+            weight > 0 || other_weight > 0,
+            "total weight of strategies must not be zero"
+        );
+
+        Self {
+            strategy,
+            other,
+            weight,
+            other_weight,
+        }
+    }
+}
+
+impl<A: ArbInterop, S: Strategy<Value = A>> Strategy for UnionArbStrategy<A, S> {
+    type Tree = UnionValueTree<A, S::Tree>;
+    type Value = A;
+
+    fn new_tree(&self, run: &mut TestRunner) -> proptest::strategy::NewTree<Self> {
+        let total_weight = u64::from(self.weight) + u64::from(self.other_weight);
+        let point = (0..total_weight).new_tree(run)?.current();
+        if point < u64::from(self.weight) {
+            Ok(UnionValueTree::Arb(Box::new(self.strategy.new_tree(run)?)))
+        } else {
+            Ok(UnionValueTree::Other(self.other.new_tree(run)?))
+        }
+    }
+}
+
+/// The [`proptest::strategy::ValueTree`] of a [`UnionArbStrategy`], which is
+/// that of the strategy that generated the value.
+#[derive(Clone, Debug)]
+pub enum UnionValueTree<A: Debug, T> {
+    /// Generated by the [`ArbStrategy`].
+    Arb(Box<ArbValueTree<A>>),
+
+    /// Generated by the other strategy.
+    Other(T),
+}
+
+impl<A: ArbInterop, T: ValueTree<Value = A>> ValueTree for UnionValueTree<A, T> {
+    type Value = A;
+
+    fn current(&self) -> Self::Value {
+        match self {
+            Self::Arb(tree) => tree.current(),
+            Self::Other(tree) => tree.current(),
+        }
+    }
+
+    fn simplify(&mut self) -> bool {
+        match self {
+            Self::Arb(tree) => tree.simplify(),
+            Self::Other(tree) => tree.simplify(),
+        }
+    }
+
+    fn complicate(&mut self) -> bool {
+        match self {
+            Self::Arb(tree) => tree.complicate(),
+            Self::Other(tree) => tree.complicate(),
+        }
+    }
+}
+
+impl<A: ArbInterop> ArbStrategy<A> {
+    /// Generates each value either with this strategy or with `other`, with
+    /// equal probability, for example to mix in special values with
+    /// [`Just`](proptest::strategy::Just).
+    ///
+    /// Like `prop_oneof![self, other]`, but with a nameable type.
+    pub fn prop_union<S: Strategy<Value = A>>(self, other: S) -> UnionArbStrategy<A, S> {
+        self.prop_union_weighted(other, 1, 1)
+    }
+
+    /// Like [`Self::prop_union`], but picks this strategy with a probability
+    /// of `self_weight` divided by the sum of both weights.
+    ///
+    /// # Panics
+    ///
+    /// Panics if both weights are zero.
+    pub fn prop_union_weighted<S: Strategy<Value = A>>(
+        self,
+        other: S,
+        self_weight: u32,
+        other_weight: u32,
+    ) -> UnionArbStrategy<A, S> {
+        UnionArbStrategy::new(self, other, self_weight, other_weight)
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, feature(coverage_attribute))]
+mod tests {
+    use proptest::strategy::Just;
+
+    use super::*;
+    use crate::arb_sized;
+
+    fn count_just(strategy: &UnionArbStrategy<u32, Just<u32>>) -> usize {
+        let mut runner = TestRunner::deterministic();
+        (0..1000)
+            .filter(|_| {
+                let tree = strategy.new_tree(&mut runner).unwrap();
+                matches!(tree, UnionValueTree::Other(_))
+            })
+            .count()
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn both_strategies_are_picked_equally() {
+        let num_just = count_just(&arb_sized::<u32>(4).prop_union(Just(7)));
+
+        assert!((400..600).contains(&num_just));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn weights_are_respected() {
+        let strategy = arb_sized::<u32>(4).prop_union_weighted(Just(7), 9, 1);
+        assert!((50..150).contains(&count_just(&strategy)));
+
+        let strategy = arb_sized::<u32>(4).prop_union_weighted(Just(7), 0, 1);
+        assert_eq!(1000, count_just(&strategy));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn values_shrink_within_their_strategy() {
+        let strategy = arb_sized::<u32>(4).prop_union_weighted(Just(7), 1, 0);
+        let mut tree = strategy.new_tree(&mut TestRunner::deterministic()).unwrap();
+        while tree.simplify() {}
+
+        assert_eq!(0, tree.current());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[should_panic(expected = "total weight of strategies must not be zero")]
+    fn zero_total_weight_panics() {
+        let _ = arb_sized::<u32>(4).prop_union_weighted(Just(7), 0, 0);
+    }
+}
@@ -0,0 +1,102 @@
+use core::fmt::Debug;
+use core::fmt::Formatter;
+
+use proptest::strategy::Strategy;
+use proptest::strategy::ValueTree;
+use proptest::test_runner::TestRunner;
+
+use crate::ArbInterop;
+use crate::ArbStrategy;
+use crate::ArbValueTree;
+
+/// A [`proptest::strategy::Strategy`] like [`ArbStrategy`] that checks an
+/// invariant of every generated value. See [`ArbStrategy::validate`].
+///
+/// This catches bugs in [`arbitrary::Arbitrary`] implementations while
+/// generating values instead of when asserting properties. The check only
+/// runs with `debug_assertions`; otherwise, the strategy behaves exactly like
+/// the underlying [`ArbStrategy`].
+#[derive(Clone)]
+pub struct ValidatedArbStrategy<A: ArbInterop, F> {
+    strategy: ArbStrategy<A>,
+    invariant: F,
+}
+
+impl<A: ArbInterop> ArbStrategy<A> {
+    /// Turns this strategy into a [`ValidatedArbStrategy`], which panics if
+    /// `invariant` returns `false` for a generated value.
+    ///
+    /// ```
+    /// # use proptest_arbitrary_adapter::arb;
+    /// let strategy = arb::<Vec<u8>>().validate(|v| v.len() <= 1024);
+    /// ```
+    ///
+    /// The panic message includes the random data the value was generated
+    /// from. With `debug_assertions` disabled, `invariant` is never called.
+    pub fn validate<F: Fn(&A) -> bool>(self, invariant: F) -> ValidatedArbStrategy<A, F> {
+        ValidatedArbStrategy {
+            strategy: self,
+            invariant,
+        }
+    }
+}
+
+impl<A: ArbInterop, F> Debug for ValidatedArbStrategy<A, F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ValidatedArbStrategy")
+            .field("strategy", &self.strategy)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<A: ArbInterop, F: Fn(&A) -> bool> Strategy for ValidatedArbStrategy<A, F> {
+    type Tree = ArbValueTree<A>;
+    type Value = A;
+
+    fn new_tree(&self, run: &mut TestRunner) -> proptest::strategy::NewTree<Self> {
+        let tree = self.strategy.new_tree(run)?;
+        #[cfg(debug_assertions)]
+        assert!(
+            (self.invariant)(&tree.current()),
+            "generated value violates invariant: {:?} (random data: {:02x?})",
+            tree.current(),
+            tree.raw_bytes(),
+        );
+
+        Ok(tree)
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, feature(coverage_attribute))]
+mod tests {
+    use super::*;
+    use crate::arb;
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn valid_values_are_generated() {
+        let strategy = arb::<u8>().validate(|_| true);
+        let mut runner = TestRunner::deterministic();
+        for _ in 0..100 {
+            strategy.new_tree(&mut runner).unwrap();
+        }
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "generated value violates invariant")]
+    fn invalid_values_panic() {
+        let strategy = arb::<u8>().validate(|_| false);
+        let _ = strategy.new_tree(&mut TestRunner::deterministic());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[cfg(not(debug_assertions))]
+    fn invariant_is_ignored_in_release_builds() {
+        let strategy = arb::<u8>().validate(|_| false);
+        strategy.new_tree(&mut TestRunner::deterministic()).unwrap();
+    }
+}
@@ -0,0 +1,235 @@
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::Display;
+use core::fmt::Formatter;
+
+use proptest::prelude::RngCore;
+use proptest::test_runner::TestRunner;
+
+use crate::ArbInterop;
+use crate::ArbStrategy;
+use crate::ArbValueTree;
+use crate::warn;
+
+/// The proportion of valid random data below which
+/// [`ArbStrategy::proportion_valid`] reports it.
+const LOW_PROPORTION_VALID: f64 = 0.1;
+
+/// Why [`ArbStrategy::always_valid`] failed.
+#[derive(Clone, Debug)]
+pub struct FailureSummary {
+    /// The random data the [`arbitrary::Arbitrary`] implementation failed on.
+    pub bytes: Vec<u8>,
+
+    /// How it failed.
+    pub failure: Failure,
+}
+
+/// How an [`arbitrary::Arbitrary`] implementation failed on some random data.
+#[derive(Clone, Debug)]
+pub enum Failure {
+    /// It returned an error.
+    Error(arbitrary::Error),
+
+    /// It panicked with the given message. Only detected with the feature
+    /// `std` on targets that unwind on panics, unlike `wasm32`; otherwise, the
+    /// panic propagates.
+    Panic(String),
+}
+
+impl Display for FailureSummary {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let bytes = &self.bytes;
+        match &self.failure {
+            Failure::Error(e) => write!(f, "error `{e}` for bytes {bytes:?}"),
+            Failure::Panic(message) => write!(f, "panic `{message}` for bytes {bytes:?}"),
+        }
+    }
+}
+
+impl<A: ArbInterop> ArbStrategy<A> {
+    /// Checks that the [`arbitrary::Arbitrary`] implementation accepts random
+    /// data of the sizes this strategy uses: generates `n` values, failing on
+    /// the first error or panic instead of retrying.
+    ///
+    /// Not a strategy, but a helper for the test suites of
+    /// [`arbitrary::Arbitrary`] implementations. The random data is
+    /// deterministic, so repeated checks use the same data.
+    ///
+    /// ```
+    /// # use proptest_arbitrary_adapter::arb;
+    /// assert!(arb::<(u8, bool, char)>().always_valid(1000).is_ok());
+    /// ```
+    pub fn always_valid(&self, n: usize) -> Result<(), FailureSummary> {
+        let mut run = TestRunner::deterministic();
+        for _ in 0..n {
+            let bytes = self.random_bytes(&mut run);
+            if let Err(failure) = Self::check(&bytes) {
+                return Err(FailureSummary { bytes, failure });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The proportion of random data of the sizes this strategy uses that the
+    /// [`arbitrary::Arbitrary`] implementation accepts, among `n` samples. Or
+    /// 1 if `n` is zero.
+    ///
+    /// A diagnostic for tuning [`arbitrary::Arbitrary`] implementations and
+    /// sizes: rejected random data wastes test cases. A proportion below 10%
+    /// is reported as a `tracing` warning with the feature `tracing`, and on
+    /// standard error with the feature `std` otherwise. Like
+    /// [`always_valid`](Self::always_valid), the random data is deterministic.
+    ///
+    /// ```
+    /// # use proptest_arbitrary_adapter::arb;
+    /// assert_eq!(1.0, arb::<u32>().proportion_valid(100));
+    /// ```
+    pub fn proportion_valid(&self, n: usize) -> f64 {
+        if n == 0 {
+            return 1.0;
+        }
+
+        let mut run = TestRunner::deterministic();
+        let num_valid = (0..n)
+            .filter(|_| {
+                let bytes = self.random_bytes(&mut run);
+                ArbValueTree::<A>::gen_one_with_size(&bytes, bytes.len()).is_ok()
+            })
+            .count();
+        let proportion = num_valid as f64 / n as f64;
+
+        if proportion < LOW_PROPORTION_VALID {
+            let percentage = 100.0 * proportion;
+            warn(format_args!(
+                "{}: only {percentage:.1}% of random data is valid, consider a larger size",
+                self.origin()
+            ));
+        }
+
+        proportion
+    }
+
+    /// Random data of a size sampled like generation does.
+    fn random_bytes(&self, run: &mut TestRunner) -> Vec<u8> {
+        let size = self.config.size_mode.sample(run).unwrap_or(0);
+        let mut bytes = vec![0; size];
+        run.rng().fill_bytes(&mut bytes);
+
+        bytes
+    }
+
+    #[cfg(feature = "std")]
+    fn check(bytes: &[u8]) -> Result<(), Failure> {
+        let generate = || ArbValueTree::<A>::gen_one_with_size(bytes, bytes.len());
+        match std::panic::catch_unwind(std::panic::AssertUnwindSafe(generate)) {
+            Ok(generated) => generated.map(drop).map_err(Failure::Error),
+            Err(payload) => {
+                let message = payload
+                    .downcast_ref::<&str>()
+                    .map(|message| String::from(*message))
+                    .or_else(|| payload.downcast_ref::<String>().cloned())
+                    .unwrap_or_default();
+                Err(Failure::Panic(message))
+            }
+        }
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn check(bytes: &[u8]) -> Result<(), Failure> {
+        ArbValueTree::<A>::gen_one_with_size(bytes, bytes.len())
+            .map(drop)
+            .map_err(Failure::Error)
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, feature(coverage_attribute))]
+mod tests {
+    use alloc::string::ToString;
+
+    use arbitrary::Arbitrary;
+
+    use super::*;
+    use crate::arb;
+    use crate::arb_sized;
+
+    /// Rejects random data with a zero first byte.
+    #[derive(Debug, Clone)]
+    struct NonZero;
+
+    impl<'a> Arbitrary<'a> for NonZero {
+        fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+            if u8::arbitrary(u)? == 0 {
+                return Err(arbitrary::Error::IncorrectFormat);
+            }
+
+            Ok(Self)
+        }
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn total_implementations_are_valid() {
+        assert!(arb::<(u8, bool, char)>().always_valid(1000).is_ok());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn first_error_is_reported() {
+        let summary = arb_sized::<NonZero>(1).always_valid(1000).unwrap_err();
+
+        assert_eq!(vec![0], summary.bytes);
+        assert!(matches!(
+            summary.failure,
+            Failure::Error(arbitrary::Error::IncorrectFormat)
+        ));
+
+        let summary = arb_sized::<NonZero>(0).always_valid(1).unwrap_err();
+        assert!(summary.to_string().starts_with("error `"));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn proportion_of_valid_random_data_is_measured() {
+        assert_eq!(1.0, arb::<u32>().proportion_valid(100));
+        assert_eq!(1.0, arb_sized::<NonZero>(1).proportion_valid(0));
+        assert_eq!(0.0, arb_sized::<NonZero>(0).proportion_valid(100));
+
+        let proportion = arb_sized::<NonZero>(1).proportion_valid(10_000);
+        assert!((proportion - 255.0 / 256.0).abs() < 0.01);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn proportion_is_deterministic() {
+        let strategy = ArbStrategy::<NonZero>::with_size_range(0, 1);
+
+        assert_eq!(
+            strategy.proportion_valid(100),
+            strategy.proportion_valid(100)
+        );
+    }
+
+    // Not on `wasm32`, where panics abort instead of unwinding.
+    #[cfg(feature = "std")]
+    #[test]
+    fn panics_are_reported() {
+        #[derive(Debug, Clone)]
+        struct Panicking;
+
+        impl<'a> Arbitrary<'a> for Panicking {
+            fn arbitrary(_: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+                panic!("boom")
+            }
+        }
+
+        let summary = arb_sized::<Panicking>(4).always_valid(1).unwrap_err();
+
+        assert_eq!(4, summary.bytes.len());
+        assert!(matches!(&summary.failure, Failure::Panic(message) if message == "boom"));
+        assert!(summary.to_string().starts_with("panic `boom`"));
+    }
+}
@@ -0,0 +1,169 @@
+use alloc::vec::Vec;
+
+use proptest::prelude::RngCore;
+use proptest::strategy::Strategy;
+use proptest::strategy::ValueTree;
+use proptest::test_runner::Reason;
+use proptest::test_runner::TestRunner;
+
+use crate::ArbInterop;
+use crate::ArbStrategy;
+use crate::ArbValueTree;
+use crate::arb;
+
+/// A [`proptest::strategy::Strategy`] that draws the size of the random data
+/// from a discrete probability distribution.
+///
+/// Useful to mirror the real-world distribution of a type's inputs, for
+/// example many small values and occasional large ones. See
+/// [`arb_weighted_sizes`].
+///
+/// Like [`arb`], gives up after the default number of
+/// [`max_retries`](crate::ArbConfig::max_retries) rejections.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct WeightedSizeArbStrategy<A: ArbInterop> {
+    /// Pairs of sizes and their weights.
+    sizes: Vec<(usize, u32)>,
+    total_weight: u64,
+
+    /// Names the strategy in failure messages, limits the number of
+    /// rejections and configures shrinking. Its sizes are unused.
+    strategy: ArbStrategy<A>,
+}
+
+impl<A: ArbInterop> WeightedSizeArbStrategy<A> {
+    /// Every size is picked with a probability of its weight divided by the sum
+    /// of all weights.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the sum of all weights is zero, including if there are no
+    /// sizes at all.
+    pub fn new(sizes: Vec<(usize, u32)>) -> Self {
+        let total_weight = sizes.iter().map(|&(_, weight)| u64::from(weight)).sum();
+        assert!(total_weight > 0, "total weight of sizes must not be zero");
+
+        Self {
+            sizes,
+            total_weight,
+            strategy: arb(),
+        }
+    }
+
+    fn sample_size(&self, run: &mut TestRunner) -> Result<usize, Reason> {
+        let mut point = (0..self.total_weight).new_tree(run)?.current();
+        for &(size, weight) in &self.sizes {
+            let weight = u64::from(weight);
+            if point < weight {
+                return Ok(size);
+            }
+            point -= weight;
+        }
+
+        unreachable!("sampled point must be less than the total weight")
+    }
+}
+
+impl<A: ArbInterop> Strategy for WeightedSizeArbStrategy<A> {
+    type Tree = ArbValueTree<A>;
+    type Value = A;
+
+    fn new_tree(&self, run: &mut TestRunner) -> proptest::strategy::NewTree<Self> {
+        self.strategy.new_tree_sized(
+            run,
+            |run, _| self.sample_size(run),
+            |run, bytes| run.rng().fill_bytes(bytes),
+            || (),
+            ArbValueTree::new,
+        )
+    }
+}
+
+/// Constructs a [`WeightedSizeArbStrategy`] for a given
+/// [`arbitrary::Arbitrary`] type from pairs of sizes and their weights.
+///
+/// # Panics
+///
+/// Panics if the sum of all weights is zero.
+pub fn arb_weighted_sizes<A: ArbInterop>(sizes: Vec<(usize, u32)>) -> WeightedSizeArbStrategy<A> {
+    WeightedSizeArbStrategy::new(sizes)
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, feature(coverage_attribute))]
+mod tests {
+    use arbitrary::Arbitrary;
+
+    use super::*;
+    use crate::test_util::Len;
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn sizes_follow_their_weights() {
+        let strategy = arb_weighted_sizes::<Len>(vec![(1, 1), (10, 3), (100, 6), (1000, 0)]);
+        let mut runner = TestRunner::deterministic();
+        let mut counts = [0_usize; 3];
+        for _ in 0..1000 {
+            match strategy.new_tree(&mut runner).unwrap().current().0 {
+                1 => counts[0] += 1,
+                10 => counts[1] += 1,
+                100 => counts[2] += 1,
+                size => panic!("unexpected size {size}"),
+            }
+        }
+
+        // Expected counts are 100, 300, and 600, with standard deviations of
+        // roughly 10, 15, and 15. Allow for five of those.
+        assert!(counts[0].abs_diff(100) <= 50, "{counts:?}");
+        assert!(counts[1].abs_diff(300) <= 75, "{counts:?}");
+        assert!(counts[2].abs_diff(600) <= 75, "{counts:?}");
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn rejected_data_is_retried_with_new_size() {
+        #[derive(Debug, Clone)]
+        struct AtLeast8;
+
+        impl<'a> Arbitrary<'a> for AtLeast8 {
+            fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+                u.bytes(8)?;
+                Ok(Self)
+            }
+        }
+
+        let strategy = arb_weighted_sizes::<AtLeast8>(vec![(0, 9), (8, 1)]);
+        let mut runner = TestRunner::deterministic();
+        for _ in 0..10 {
+            strategy.new_tree(&mut runner).unwrap();
+        }
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn too_many_rejections_fail() {
+        #[derive(Debug, Clone)]
+        struct Never;
+
+        impl<'a> Arbitrary<'a> for Never {
+            fn arbitrary(_: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+                Err(arbitrary::Error::IncorrectFormat)
+            }
+        }
+
+        let strategy = arb_weighted_sizes::<Never>(vec![(0, 1), (4, 1)]);
+        let reason = strategy
+            .new_tree(&mut TestRunner::deterministic())
+            .unwrap_err();
+
+        let expected = format!("{}: too many rejections", core::any::type_name::<Never>());
+        assert!(reason.message().starts_with(&expected));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[should_panic]
+    fn total_weight_must_not_be_zero() {
+        let _ = arb_weighted_sizes::<Len>(vec![(8, 0)]);
+    }
+}
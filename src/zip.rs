@@ -0,0 +1,113 @@
+use core::marker::PhantomData;
+
+use arbitrary::Arbitrary;
+use arbitrary::Unstructured;
+use proptest::strategy::Strategy;
+
+use crate::ArbInterop;
+use crate::ArbStrategy;
+
+/// A pair of values generated from the two halves of the same random data.
+#[derive(Debug, Clone)]
+struct Halves<A, B>(A, B);
+
+impl<'a, A: ArbInterop, B: ArbInterop> Arbitrary<'a> for Halves<A, B> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let bytes = u.bytes(u.len())?;
+        let (first, second) = bytes.split_at(bytes.len() / 2);
+        let a = A::arbitrary(&mut Unstructured::new(first))?;
+        let b = B::arbitrary(&mut Unstructured::new(second))?;
+
+        Ok(Self(a, b))
+    }
+}
+
+impl<A: ArbInterop> ArbStrategy<A> {
+    /// Pairs the values of this strategy with independently generated values
+    /// of the `other` strategy. The same as the tuple `(self, other)`.
+    pub fn zip<B: ArbInterop>(
+        self,
+        other: ArbStrategy<B>,
+    ) -> impl Strategy<Value = (A, B)> + Clone {
+        (self, other)
+    }
+
+    /// Pairs the values of this strategy with values of type `B`, generating
+    /// both from the same random data. `A` gets the first half of it, `B` the
+    /// second half, so the random data should be sized for both.
+    ///
+    /// Shrinking shortens the random data of both values at once. Useful to
+    /// test types that interact, like the two sides of a protocol.
+    pub fn zip_biased<B: ArbInterop>(self) -> impl Strategy<Value = (A, B)> + Clone {
+        ArbStrategy::<Halves<A, B>> {
+            config: self.config,
+            name: self.name,
+            location: self.location,
+            shrinker: self.shrinker,
+            _ph: PhantomData,
+        }
+        .prop_map(|Halves(a, b)| (a, b))
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, feature(coverage_attribute))]
+mod tests {
+    use proptest::strategy::ValueTree;
+    use proptest::test_runner::TestRunner;
+
+    use super::*;
+    use crate::ArbValueTree;
+    use crate::arb;
+    use crate::arb_sized;
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn zipped_values_match_tuple() {
+        let mut runner = TestRunner::deterministic();
+        let mut other_runner = TestRunner::deterministic();
+        for _ in 0..10 {
+            let pair = arb::<u64>()
+                .zip(arb::<bool>())
+                .new_tree(&mut runner)
+                .unwrap();
+            let expected = (arb::<u64>(), arb::<bool>())
+                .new_tree(&mut other_runner)
+                .unwrap();
+            assert_eq!(expected.current(), pair.current());
+        }
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn biased_values_share_random_data() {
+        let strategy = arb_sized::<[u8; 4]>(8).zip_biased::<[u8; 4]>();
+        let mut runner = TestRunner::deterministic();
+        for _ in 0..10 {
+            let (a, b) = strategy.new_tree(&mut runner).unwrap().current();
+            assert_ne!(a, b);
+        }
+
+        let bytes = [1, 2, 3, 4, 5, 6, 7, 8];
+        let tree = ArbValueTree::<Halves<u32, u32>>::new(bytes.to_vec()).unwrap();
+        let Halves(a, b) = tree.current();
+        assert_eq!(
+            u32::arbitrary(&mut Unstructured::new(&bytes[..4])).unwrap(),
+            a
+        );
+        assert_eq!(
+            u32::arbitrary(&mut Unstructured::new(&bytes[4..])).unwrap(),
+            b
+        );
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn biased_values_shrink_together() {
+        let strategy = arb_sized::<u32>(8).zip_biased::<u32>();
+        let mut tree = strategy.new_tree(&mut TestRunner::deterministic()).unwrap();
+        while tree.simplify() {}
+
+        assert_eq!((0, 0), tree.current());
+    }
+}
@@ -0,0 +1,37 @@
+// With the feature `derive`, `#[derive(ArbInterop)]` checks at compile time
+// that a type can be used with this crate.
+
+#![cfg(feature = "derive")]
+
+use proptest::strategy::Strategy;
+use proptest::strategy::ValueTree;
+use proptest::test_runner::TestRunner;
+use proptest_arbitrary_adapter::ArbInterop;
+use proptest_arbitrary_adapter::arb;
+
+#[derive(Debug, Clone, arbitrary::Arbitrary, ArbInterop)]
+pub struct Rgb {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+#[derive(Debug, Clone, arbitrary::Arbitrary, ArbInterop)]
+pub enum Shape<T> {
+    Point,
+    Circle(T),
+    Polygon(Vec<T>),
+}
+
+fn generate<A: ArbInterop>() -> A {
+    arb()
+        .new_tree(&mut TestRunner::deterministic())
+        .unwrap()
+        .current()
+}
+
+#[test]
+fn derived_types_can_be_generated() {
+    let _: Rgb = generate();
+    let _: Shape<Rgb> = generate();
+}
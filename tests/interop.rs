@@ -15,7 +15,7 @@ use proptest::prelude::*;
 use proptest_arbitrary_adapter::arb;
 use test_strategy::proptest;
 
-#[proptest]
+#[proptest(failure_persistence = None)]
 #[should_panic]
 fn always_red(#[strategy(arb())] color: Rgb) {
     prop_assert!(color.g == 0 || color.r > color.g);
@@ -0,0 +1,79 @@
+// Suppose a property test fails and you want to keep the failing input around,
+// for example to add it to a regression corpus. The bytes the failing value was
+// generated from can be saved to disk and replayed later.
+
+use std::path::PathBuf;
+
+use proptest::strategy::Strategy;
+use proptest::strategy::ValueTree;
+use proptest::test_runner::TestRunner;
+use proptest_arbitrary_adapter::ArbValueTree;
+use proptest_arbitrary_adapter::arb;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, arbitrary::Arbitrary)]
+pub struct Rgb {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+fn is_red(color: &Rgb) -> bool {
+    color.g == 0 || color.r > color.g
+}
+
+/// Finds a minimal color that is not red, the way proptest's test runner would.
+fn minimal_non_red_color() -> ArbValueTree<Rgb> {
+    let mut runner = TestRunner::deterministic();
+    let mut tree = loop {
+        let tree = arb::<Rgb>().new_tree(&mut runner).unwrap();
+        if !is_red(&tree.current()) {
+            break tree;
+        }
+    };
+
+    let mut can_shrink = tree.simplify();
+    while can_shrink {
+        can_shrink = if is_red(&tree.current()) {
+            tree.complicate()
+        } else {
+            tree.simplify()
+        };
+    }
+
+    tree
+}
+
+fn corpus_file(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("{name}-{}", std::process::id()))
+}
+
+#[test]
+fn failure_can_be_saved_and_replayed() {
+    let tree = minimal_non_red_color();
+    let failing_color = tree.current();
+    assert!(!is_red(&failing_color));
+
+    let path = corpus_file("failure_can_be_saved_and_replayed");
+    std::fs::write(&path, &tree.raw_bytes()[..tree.current_size()]).unwrap();
+    let bytes = std::fs::read(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    let replayed = ArbValueTree::<Rgb>::from_bytes(bytes).unwrap();
+    assert_eq!(failing_color, replayed.current());
+}
+
+#[test]
+fn raw_bytes_replay_current_value_while_shrinking() {
+    let mut runner = TestRunner::deterministic();
+    let mut tree = arb::<Rgb>().new_tree(&mut runner).unwrap();
+    let num_bytes = tree.raw_bytes().len();
+    assert_eq!(num_bytes, tree.current_size());
+
+    while tree.simplify() {
+        let prefix = tree.raw_bytes()[..tree.current_size()].to_vec();
+        let replayed = ArbValueTree::<Rgb>::from_bytes(prefix).unwrap();
+        assert_eq!(tree.current(), replayed.current());
+    }
+    assert_eq!(num_bytes, tree.raw_bytes().len());
+    assert_eq!(0, tree.current_size());
+}